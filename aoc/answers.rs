@@ -0,0 +1,118 @@
+// Imports
+use std::{
+    collections::BTreeMap,
+    fmt,
+    fs,
+    io::ErrorKind,
+    path::Path,
+    str::FromStr
+};
+
+// Answer compared to a known one
+#[derive(Debug,Clone,Eq,PartialEq)]
+pub enum Verdict {
+    CORRECT,
+    WRONG(String),
+    NEW
+}
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::CORRECT => write!(f, "correct"),
+            Self::WRONG(expected) => write!(f, "WRONG, expected {}", expected.replace('\n', "\\n")),
+            Self::NEW => write!(f, "new")
+        }
+    }
+}
+
+// Known answers by day & part, lines '<day> <part> <answer>' (line breaks of answers as '\n', '#' comments)
+#[derive(Debug,Clone,Default,Eq,PartialEq)]
+pub struct Answers {
+    answers: BTreeMap<(u8,usize),String>
+}
+impl Answers {
+    pub fn verdict(&self, day: u8, part: usize, answer: &str) -> Verdict {
+        match self.answers.get(&(day, part)) {
+            Some(known) if known == answer => Verdict::CORRECT,
+            Some(known) => Verdict::WRONG(known.clone()),
+            None => Verdict::NEW
+        }
+    }
+    pub fn record(&mut self, day: u8, part: usize, answer: &str) {
+        self.answers.insert((day, part), answer.to_string());
+    }
+
+    // Files, missing one is empty
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self,String> {
+        match fs::read_to_string(&path) {
+            Ok(text) => text.parse().map_err(|err| format!("{}: {}", path.as_ref().display(), err)),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(format!("Couldn't read answers file {}: {}", path.as_ref().display(), err))
+        }
+    }
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(),String> {
+        fs::write(&path, self.to_string()).map_err(|err| format!("Couldn't write answers file {}: {}", path.as_ref().display(), err) )
+    }
+}
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "# Known answers: day, part, answer")?;
+        for ((day, part), answer) in &self.answers {
+            writeln!(f, "{} {} {}", day, part, answer.replace('\n', "\\n"))?;
+        }
+        Ok(())
+    }
+}
+impl FromStr for Answers {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut answers = BTreeMap::new();
+        for (i, line) in s.lines().enumerate() {
+            if line.trim().is_empty() || line.trim_start().starts_with('#') {
+                continue;
+            }
+            let mut tokens = line.splitn(3, ' ');
+            let (day, part, answer) = match (tokens.next(), tokens.next(), tokens.next()) {
+                (Some(day), Some(part), Some(answer)) => (day, part, answer),
+                _ => return Err(format!("Line {}: Expected day, part and answer", i+1))
+            };
+            answers.insert(
+                (
+                    day.parse().map_err(|_| format!("Line {}: Invalid day: {}", i+1, day))?,
+                    part.parse().map_err(|_| format!("Line {}: Invalid part: {}", i+1, part))?
+                ),
+                answer.replace("\\n", "\n")
+            );
+        }
+        Ok(Self {answers})
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_and_print() {
+        let answers = "# Comment\n\n1 1 3154112\n8 2 #..#\\n####\n".parse::<Answers>().expect("Answers should be valid!");
+        assert_eq!(answers.verdict(1, 1, "3154112"), Verdict::CORRECT);
+        assert_eq!(answers.verdict(8, 2, "#..#\n####"), Verdict::CORRECT);
+        assert_eq!(answers.to_string().parse::<Answers>(), Ok(answers));
+    }
+    #[test]
+    fn malformed_lines() {
+        assert_eq!("1 1 42\n2 1".parse::<Answers>(), Err("Line 2: Expected day, part and answer".to_string()));
+        assert_eq!("x 1 42".parse::<Answers>(), Err("Line 1: Invalid day: x".to_string()));
+        assert_eq!("256 1 42".parse::<Answers>(), Err("Line 1: Invalid day: 256".to_string()));
+        assert_eq!("1 -1 42".parse::<Answers>(), Err("Line 1: Invalid part: -1".to_string()));
+    }
+    #[test]
+    fn duplicates_and_missing_parts() {
+        // Later lines replace earlier ones
+        let answers = "1 1 41\n1 1 42\n".parse::<Answers>().expect("Answers should be valid!");
+        assert_eq!(answers.verdict(1, 1, "42"), Verdict::CORRECT);
+        assert_eq!(answers.verdict(1, 1, "41"), Verdict::WRONG("42".to_string()));
+        assert_eq!(answers.verdict(1, 2, "42"), Verdict::NEW);
+        assert_eq!(answers.verdict(2, 1, "42"), Verdict::NEW);
+    }
+}
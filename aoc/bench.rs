@@ -0,0 +1,169 @@
+// Imports
+use std::{
+    alloc::{GlobalAlloc,Layout,System},
+    collections::BTreeMap,
+    fmt,
+    fs,
+    io::ErrorKind,
+    path::Path,
+    str::FromStr,
+    sync::atomic::{AtomicUsize,Ordering},
+    time::{Duration,Instant}
+};
+use crate::{
+    output::format_duration,
+    solvers::Solver
+};
+
+// System allocator counting allocations
+struct CountingAllocator;
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc_zeroed(layout)
+    }
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+// Durations of runs and median of their allocation counts (incl. reallocations)
+#[derive(Debug,Clone,Copy,Eq,PartialEq)]
+pub struct Statistics {
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+    pub allocations: usize
+}
+impl fmt::Display for Statistics {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "min {}, median {}, p95 {}, {} allocations", format_duration(self.min), format_duration(self.median), format_duration(self.p95), self.allocations)
+    }
+}
+// Change of current to baseline duration in percent
+pub fn relative_change(baseline: Duration, current: Duration) -> f64 {
+    (current.as_secs_f64() / baseline.as_secs_f64().max(f64::MIN_POSITIVE) - 1.0) * 100.0
+}
+
+// Solver runs after one warm-up run
+pub fn measure(solver: Solver, input: &str, runs: usize) -> Result<Statistics,String> {
+    solver(input)?;
+    let mut samples = (0..runs.max(1))
+        .map(|_| {
+            let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+            let start = Instant::now();
+            let answer = solver(input);
+            let elapsed = start.elapsed();
+            answer.map(|_| (elapsed, ALLOCATIONS.load(Ordering::Relaxed) - allocations))
+        })
+        .collect::<Result<Vec<_>,String>>()?;
+    samples.sort();
+    let mut allocations = samples.iter().map(|(_, allocations)| *allocations).collect::<Vec<_>>();
+    allocations.sort_unstable();
+    Ok(Statistics {
+        min: samples[0].0,
+        median: samples[samples.len() / 2].0,
+        p95: samples[(samples.len() * 95).div_ceil(100) - 1].0,
+        allocations: allocations[allocations.len() / 2]
+    })
+}
+
+// Median durations by day & part, lines '<day> <part> <nanoseconds>'
+#[derive(Debug,Clone,Default,Eq,PartialEq)]
+pub struct Baseline {
+    medians: BTreeMap<(u8,usize),Duration>
+}
+impl Baseline {
+    pub fn get(&self, day: u8, part: usize) -> Option<Duration> {
+        self.medians.get(&(day, part)).copied()
+    }
+    pub fn set(&mut self, day: u8, part: usize, median: Duration) {
+        self.medians.insert((day, part), median);
+    }
+    // Relative change to baseline when beyond threshold (in percent)
+    pub fn regression(&self, day: u8, part: usize, median: Duration, threshold: f64) -> Option<f64> {
+        let change = relative_change(self.get(day, part)?, median);
+        if change > threshold {Some(change)} else {None}
+    }
+
+    // Files, missing one is empty
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self,String> {
+        match fs::read_to_string(&path) {
+            Ok(text) => text.parse().map_err(|err| format!("{}: {}", path.as_ref().display(), err)),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(format!("Couldn't read baseline file {}: {}", path.as_ref().display(), err))
+        }
+    }
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(),String> {
+        fs::write(&path, self.to_string()).map_err(|err| format!("Couldn't write baseline file {}: {}", path.as_ref().display(), err) )
+    }
+}
+impl fmt::Display for Baseline {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "# Benchmark baseline: day, part, median nanoseconds")?;
+        for ((day, part), median) in &self.medians {
+            writeln!(f, "{} {} {}", day, part, median.as_nanos())?;
+        }
+        Ok(())
+    }
+}
+impl FromStr for Baseline {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut medians = BTreeMap::new();
+        for (i, line) in s.lines().enumerate() {
+            if line.trim().is_empty() || line.trim_start().starts_with('#') {
+                continue;
+            }
+            let tokens = line.split_whitespace().collect::<Vec<_>>();
+            if tokens.len() != 3 {
+                return Err(format!("Line {}: Expected day, part and nanoseconds", i+1));
+            }
+            medians.insert(
+                (
+                    tokens[0].parse().map_err(|_| format!("Line {}: Invalid day: {}", i+1, tokens[0]))?,
+                    tokens[1].parse().map_err(|_| format!("Line {}: Invalid part: {}", i+1, tokens[1]))?
+                ),
+                Duration::from_nanos(tokens[2].parse().map_err(|_| format!("Line {}: Invalid nanoseconds: {}", i+1, tokens[2]))?)
+            );
+        }
+        Ok(Self {medians})
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn baseline_file() {
+        let baseline = "# Benchmark baseline\n\n1 2 1500\n 10 1 42 \n".parse::<Baseline>();
+        assert_eq!(baseline.as_ref().map(|baseline| (baseline.get(1, 2), baseline.get(10, 1), baseline.get(1, 1))), Ok((Some(Duration::from_nanos(1500)), Some(Duration::from_nanos(42)), None)));
+        let baseline = baseline.expect("Baseline should parse!");
+        assert_eq!(baseline.to_string().parse(), Ok(baseline));
+        assert_eq!("1 2".parse::<Baseline>(), Err("Line 1: Expected day, part and nanoseconds".to_string()));
+        assert_eq!("1 1 5\nx 1 5".parse::<Baseline>(), Err("Line 2: Invalid day: x".to_string()));
+        assert_eq!("1 -1 5".parse::<Baseline>(), Err("Line 1: Invalid part: -1".to_string()));
+        assert_eq!("1 1 5ns".parse::<Baseline>(), Err("Line 1: Invalid nanoseconds: 5ns".to_string()));
+    }
+    #[test]
+    fn regression_threshold() {
+        let mut baseline = Baseline::default();
+        baseline.set(3, 1, Duration::from_millis(100));
+        assert_eq!(baseline.regression(3, 1, Duration::from_millis(105), 10.0), None);
+        assert_eq!(baseline.regression(3, 1, Duration::from_millis(90), 10.0), None);
+        assert!(baseline.regression(3, 1, Duration::from_millis(125), 10.0).is_some_and(|change| (change - 25.0).abs() < 1e-9));
+        assert_eq!(baseline.regression(3, 2, Duration::from_millis(500), 10.0), None);
+    }
+}
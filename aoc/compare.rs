@@ -0,0 +1,124 @@
+// Imports
+use std::{
+    fs,
+    io::Write,
+    path::{Path,PathBuf},
+    process::{Command,Stdio},
+    thread
+};
+
+// Output lines without formatting differences: line endings, trailing spaces, blank lines and Rust options (Kotlin nullables)
+pub fn normalize(output: &str) -> Vec<String> {
+    output.lines()
+        .map(|line| unwrap_options(line.trim_end()))
+        .filter(|line| !line.is_empty())
+        .collect()
+}
+fn unwrap_options(line: &str) -> String {
+    let mut line = line.replace("None", "null");
+    while let Some(start) = line.find("Some(") {
+        // Matching parenthesis of option
+        let inner = start + "Some(".len();
+        let mut depth = 1;
+        let end = line[inner..].char_indices()
+            .find(|(_, chr)| {
+                match chr {
+                    '(' => depth += 1,
+                    ')' => depth -= 1,
+                    _ => ()
+                }
+                depth == 0
+            })
+            .map(|(index, _)| inner + index);
+        match end {
+            Some(end) => line = format!("{}{}{}", &line[..start], &line[inner..end], &line[end+1..]),
+            None => break
+        }
+    }
+    line
+}
+
+// Differing lines (1-based), missing ones on the shorter side
+pub type Difference<'a> = (usize,Option<&'a str>,Option<&'a str>);
+pub fn differences<'a>(rust: &'a [String], kotlin: &'a [String]) -> Vec<Difference<'a>> {
+    (0..rust.len().max(kotlin.len()))
+        .map(|index| (index + 1, rust.get(index).map(String::as_str), kotlin.get(index).map(String::as_str)))
+        .filter(|(_, rust, kotlin)| rust != kotlin)
+        .collect()
+}
+
+// Standard output of a program fed with input
+fn output(command: &mut Command, input: &str) -> Result<String,String> {
+    let mut child = command
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::inherit())
+        .spawn()
+        .map_err(|err| format!("Couldn't start {:?}: {}", command.get_program(), err))?;
+    let mut stdin = child.stdin.take().expect("Standard input should be piped!");
+    let input = input.to_string();
+    // Feed separately, program could fill its output pipe before reading all input
+    let feeder = thread::spawn(move || stdin.write_all(input.as_bytes()));
+    let result = child.wait_with_output().map_err(|err| format!("{:?} failed: {}", command.get_program(), err))?;
+    // Programs may stop reading early
+    let _ = feeder.join();
+    if !result.status.success() {
+        return Err(format!("{:?} exited with {}", command.get_program(), result.status));
+    }
+    String::from_utf8(result.stdout).map_err(|_| format!("Output of {:?} isn't text!", command.get_program()))
+}
+
+// Rust reference: binary of the day crate
+pub fn rust_output(workspace: &Path, day: u8, input: &str) -> Result<String,String> {
+    output(
+        Command::new(option_env!("CARGO").unwrap_or("cargo"))
+            .args(["run", "--quiet", "--release", "--package", &format!("day{}", day)])
+            .current_dir(workspace),
+        input
+    )
+}
+
+// Kotlin port: given jar or 'dayN/main.kt' built like by 'run.sh' when outdated
+pub fn kotlin_output(workspace: &Path, day: u8, jar: &Option<PathBuf>, input: &str) -> Result<String,String> {
+    let jar = match jar {
+        Some(jar) => jar.clone(),
+        None => build_kotlin(workspace, day)?
+    };
+    output(Command::new("java").arg("-jar").arg(&jar), input)
+}
+fn build_kotlin(workspace: &Path, day: u8) -> Result<PathBuf,String> {
+    let source = workspace.join(format!("day{}", day)).join("main.kt");
+    let jar = workspace.join("out").join(format!("day{}.jar", day));
+    let modified = |path: &Path| fs::metadata(path).and_then(|metadata| metadata.modified()).ok();
+    let source_modified = modified(&source).ok_or(format!("No Kotlin solution {}!", source.display()))?;
+    if modified(&jar).is_some_and(|jar_modified| jar_modified >= source_modified) {
+        return Ok(jar);
+    }
+    fs::create_dir_all(workspace.join("out")).map_err(|err| format!("Couldn't create output directory: {}", err))?;
+    let status = Command::new("kotlinc")
+        .args(["-include-runtime", "-jvm-target", "1.8", "-d"])
+        .arg(&jar)
+        .arg(&source)
+        .status()
+        .map_err(|err| format!("Couldn't start kotlinc (https://github.com/JetBrains/kotlin/releases/latest): {}", err))?;
+    if status.success() {Ok(jar)} else {Err(format!("Kotlin compilation of {} failed!", source.display()))}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalization() {
+        assert_eq!(normalize("Nearest distance: Some(293)\r\nShortest distance: None  \n\n"), ["Nearest distance: 293", "Shortest distance: null"]);
+        assert_eq!(normalize("Fuel: (Some((1, 2)), 3)"), ["Fuel: ((1, 2), 3)"]);
+        assert_eq!(normalize("Part 2:\n #  #\n# ## "), ["Part 2:", " #  #", "# ##"]);
+    }
+    #[test]
+    fn differing_lines() {
+        let rust = normalize("Part 1: 1\nPart 2: 2\n");
+        let kotlin = normalize("Part 1: 1\nPart 2: 3\nExtra");
+        assert_eq!(differences(&rust, &kotlin), [(2, Some("Part 2: 2"), Some("Part 2: 3")), (3, None, Some("Extra"))]);
+        assert!(differences(&rust, &rust).is_empty());
+    }
+}
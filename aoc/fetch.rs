@@ -0,0 +1,157 @@
+// Imports
+use std::{
+    env,
+    fs,
+    io::Write,
+    path::{Path,PathBuf},
+    process::{Command,Stdio}
+};
+
+// Puzzle site, overridable for local servers
+pub const DEFAULT_URL: &str = "https://adventofcode.com";
+const YEAR: u16 = 2019;
+const USER_AGENT: &str = "aoc-runner (Advent of Code 2019 solutions; fetches each input once and caches it)";
+
+// Session cookie from environment, otherwise from a config file
+pub const SESSION_VARIABLE: &str = "AOC_SESSION";
+pub fn session_file() -> Option<PathBuf> {
+    env::var_os("XDG_CONFIG_HOME").map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+        .map(|config| config.join("aoc").join("session"))
+}
+pub fn read_session(file: &Option<PathBuf>) -> Result<String,String> {
+    if file.is_none() {
+        if let Some(session) = env::var(SESSION_VARIABLE).ok().filter(|session| !session.trim().is_empty()) {
+            return Ok(session.trim().to_string());
+        }
+    }
+    let path = file.clone().or_else(session_file).ok_or("No session file location (set HOME or XDG_CONFIG_HOME)")?;
+    let session = fs::read_to_string(&path)
+        .map_err(|err| format!("No session cookie in ${} and couldn't read session file {}: {}", SESSION_VARIABLE, path.display(), err))?;
+    match session.trim() {
+        "" => Err(format!("Session file {} is empty!", path.display())),
+        session => Ok(session.to_string())
+    }
+}
+
+// Cached inputs are non-empty files
+pub fn is_cached(path: &Path) -> bool {
+    fs::metadata(path).is_ok_and(|metadata| metadata.len() > 0)
+}
+
+// Input of a day into file, requested at most once; whether it was downloaded
+pub fn fetch(day: u8, path: &Path, url: &str, session: &str) -> Result<bool,String> {
+    if is_cached(path) {
+        return Ok(false);
+    }
+    let input = download(&format!("{}/{}/day/{}/input", url.trim_end_matches('/'), YEAR, day), session)?;
+    if input.trim().is_empty() {
+        return Err(format!("Empty input received for day {}!", day));
+    }
+    // Whole file or none
+    let partial = path.with_extension("txt.part");
+    fs::write(&partial, &input)
+        .and_then(|_| fs::rename(&partial, path))
+        .map_err(|err| format!("Couldn't write input {}: {}", path.display(), err))?;
+    Ok(true)
+}
+
+// Quoted value of curl configuration, without line breaks or other control characters to end it early
+fn quote(value: &str) -> Result<String,String> {
+    if value.chars().any(char::is_control) {
+        return Err(format!("Control characters not allowed in request value: {:?}", value));
+    }
+    Ok(format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\"")))
+}
+
+// Single GET by curl (for TLS), cookie passed by standard input to keep it out of process listings
+fn download(url: &str, session: &str) -> Result<String,String> {
+    // Whole configuration valid before curl starts
+    let config = format!("url = {}\nuser-agent = {}\nheader = {}\n", quote(url)?, quote(USER_AGENT)?, quote(&format!("Cookie: session={}", session))?);
+    let mut curl = Command::new("curl")
+        .args(["--silent", "--show-error", "--fail", "--retry", "0", "--max-time", "30", "--config", "-"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|err| format!("Couldn't start curl: {}", err))?;
+    // Curl gets waited for even when the configuration didn't get through
+    let passed = curl.stdin.take().expect("Standard input should be piped!").write_all(config.as_bytes());
+    let output = curl.wait_with_output().map_err(|err| format!("Curl failed: {}", err))?;
+    passed.map_err(|err| format!("Couldn't pass request to curl: {}", err))?;
+    if !output.status.success() {
+        return Err(format!("Request of {} failed: {}", url, String::from_utf8_lossy(&output.stderr).trim()));
+    }
+    String::from_utf8(output.stdout).map_err(|_| format!("Input of {} isn't text!", url))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        io::{BufRead,BufReader},
+        net::TcpListener,
+        sync::{Arc,Mutex},
+        thread
+    };
+
+    // Local server answering every request with status and body, remembering request heads
+    fn serve(status: &'static str, body: &'static str) -> (String, Arc<Mutex<Vec<String>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("Local port should be free!");
+        let url = format!("http://{}", listener.local_addr().expect("Bound listener has an address!"));
+        let requests = Arc::new(Mutex::new(vec![]));
+        let received = requests.clone();
+        thread::spawn(move || {
+            for mut stream in listener.incoming().flatten() {
+                let head = BufReader::new(&stream).lines()
+                    .map_while(Result::ok)
+                    .take_while(|line| !line.is_empty())
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                received.lock().expect("Requests lock shouldn't be poisoned!").push(head);
+                let _ = write!(stream, "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}", status, body.len(), body);
+            }
+        });
+        (url, requests)
+    }
+    fn temporary_input(name: &str) -> PathBuf {
+        let path = env::temp_dir().join(format!("aoc-fetch-{}-{}.txt", name, std::process::id()));
+        let _ = fs::remove_file(&path);
+        path
+    }
+
+    #[test]
+    fn fetches_once_and_caches() {
+        let (url, requests) = serve("200 OK", "1\n2\n3\n");
+        let path = temporary_input("cache");
+        assert_eq!(fetch(4, &path, &url, "secret"), Ok(true));
+        assert_eq!(fetch(4, &path, &url, "secret"), Ok(false));
+        assert_eq!(fs::read_to_string(&path).ok().as_deref(), Some("1\n2\n3\n"));
+        let requests = requests.lock().expect("Requests lock shouldn't be poisoned!");
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].starts_with("GET /2019/day/4/input "));
+        assert!(requests[0].contains("Cookie: session=secret"));
+        assert!(requests[0].contains(&format!("User-Agent: {}", USER_AGENT)));
+        fs::remove_file(&path).expect("Fetched input should be removable!");
+    }
+
+    #[test]
+    fn session_is_escaped() {
+        let (url, requests) = serve("200 OK", "42\n");
+        let path = temporary_input("escape");
+        assert_eq!(fetch(1, &path, &url, "a\"b\\c"), Ok(true));
+        assert!(fetch(2, &temporary_input("inject"), &url, "x\"\noutput = \"/tmp/injected").is_err());
+        let requests = requests.lock().expect("Requests lock shouldn't be poisoned!");
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].lines().any(|line| line == "Cookie: session=a\"b\\c"), "{}", requests[0]);
+        fs::remove_file(&path).expect("Fetched input should be removable!");
+    }
+    #[test]
+    fn failed_request_writes_nothing() {
+        let (url, requests) = serve("400 Bad Request", "Please log in to get your puzzle input.");
+        let path = temporary_input("failure");
+        assert!(fetch(5, &path, &url, "expired").is_err());
+        assert!(!path.exists());
+        assert_eq!(requests.lock().expect("Requests lock shouldn't be poisoned!").len(), 1);
+    }
+}
@@ -0,0 +1,377 @@
+// Advent of Code runner
+#![allow(clippy::upper_case_acronyms)]
+
+// Modules
+mod answers;
+mod bench;
+mod compare;
+mod fetch;
+mod output;
+mod programs;
+mod scaffold;
+mod solvers;
+
+// Imports
+use std::{
+    env,
+    fs,
+    path::{Path,PathBuf},
+    process,
+    time::Instant
+};
+use intcode::{
+    IntcodeResult,
+    Processor,
+    decompile::decompile,
+    dump::MemoryDump,
+    stats::{DynamicStats,StaticStats}
+};
+use answers::*;
+use bench::*;
+use compare::*;
+use fetch::*;
+use output::*;
+use programs::*;
+use scaffold::*;
+use solvers::*;
+
+// Command line
+const USAGE: &str = "Usage:
+  aoc run <day> [--part 1|2] [--input path] [--answers path] [--record] [--format text|json]
+  aoc run --all [--answers path] [--record] [--format text|json]
+  aoc bench <day>|--all [--part 1|2] [--input path] [--runs n] [--baseline path] [--save-baseline] [--threshold percent]
+  aoc fetch <day>|--all [--url url] [--session path]
+  aoc new <day>
+  aoc compare <day>|--all [--input path] [--jar path]
+  aoc decompile <day> [--input path]
+  aoc stats <day> [--input path] [--values n,...]
+  aoc dump <day> [--input path] [--values n,...] [--until address]
+Any command takes [--workspace path], by default the Cargo workspace containing the current directory";
+struct Options {
+    workspace: PathBuf,
+    days: Vec<u8>,
+    all: bool,
+    part: Option<usize>,
+    input: Option<PathBuf>,
+    answers: PathBuf,
+    record: bool,
+    format: Format,
+    runs: usize,
+    baseline: PathBuf,
+    save_baseline: bool,
+    threshold: f64,
+    url: String,
+    session: Option<PathBuf>,
+    jar: Option<PathBuf>,
+    values: Vec<isize>,
+    until: Option<usize>
+}
+fn parse_options(args: &[String]) -> Result<Options,String> {
+    let mut options = Options {
+        workspace: PathBuf::new(),
+        days: vec![],
+        all: false,
+        part: None,
+        input: None,
+        answers: PathBuf::new(),
+        record: false,
+        format: Format::TEXT,
+        runs: 10,
+        baseline: PathBuf::new(),
+        save_baseline: false,
+        threshold: 10.0,
+        url: DEFAULT_URL.to_string(),
+        session: None,
+        jar: None,
+        values: vec![],
+        until: None
+    };
+    let (mut workspace, mut answers, mut baseline) = (None, None, None);
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--workspace" => workspace = Some(PathBuf::from(args.next().ok_or("Missing path after --workspace")?)),
+            "--all" => {
+                options.days = DAYS.iter().map(|day| day.day).collect();
+                options.all = true;
+            }
+            "--part" => options.part = match args.next().map(String::as_str) {
+                Some("1") => Some(1),
+                Some("2") => Some(2),
+                part => return Err(format!("Part must be 1 or 2, got {:?}", part))
+            },
+            "--input" => options.input = Some(args.next().ok_or("Missing path after --input")?.into()),
+            "--answers" => answers = Some(args.next().ok_or("Missing path after --answers")?.into()),
+            "--record" => options.record = true,
+            "--format" => options.format = args.next().ok_or("Missing format after --format")?.parse()?,
+            "--runs" => options.runs = match args.next().map(|runs| runs.parse()) {
+                Some(Ok(runs)) if runs > 0 => runs,
+                _ => return Err("Runs must be a positive number".to_string())
+            },
+            "--baseline" => baseline = Some(args.next().ok_or("Missing path after --baseline")?.into()),
+            "--save-baseline" => options.save_baseline = true,
+            "--threshold" => options.threshold = args.next()
+                .and_then(|threshold| threshold.parse().ok())
+                .ok_or("Threshold must be a number (percent)")?,
+            "--url" => options.url = args.next().ok_or("Missing URL after --url")?.clone(),
+            "--session" => options.session = Some(args.next().ok_or("Missing path after --session")?.into()),
+            "--jar" => options.jar = Some(args.next().ok_or("Missing path after --jar")?.into()),
+            "--values" => options.values = parsing::parse_separated(args.next().ok_or("Missing values after --values")?, ',')
+                .map_err(|err| format!("Invalid values: {}", err))?,
+            "--until" => options.until = Some(args.next()
+                .and_then(|address| address.parse().ok())
+                .ok_or("Breakpoint must be an address")?),
+            day => options.days.push(day.parse().map_err(|_| format!("Invalid day: {}", day))?)
+        }
+    }
+    // Workspace files by default
+    options.workspace = match workspace {
+        Some(workspace) => workspace,
+        None => find_workspace()?
+    };
+    options.answers = answers.unwrap_or_else(|| options.workspace.join("answers.txt"));
+    options.baseline = baseline.unwrap_or_else(|| options.workspace.join("bench_baseline.txt"));
+    match options.days.len() {
+        0 => Err("Missing day (or --all)".to_string()),
+        1 => Ok(options),
+        _ if options.input.is_some() => Err("--input needs a single day".to_string()),
+        _ => Ok(options)
+    }
+}
+
+// Nearest directory with a workspace manifest, from the current directory upwards
+fn find_workspace() -> Result<PathBuf,String> {
+    let current = env::current_dir().map_err(|err| format!("Couldn't get current directory: {}", err))?;
+    current.ancestors()
+        .find(|dir| fs::read_to_string(dir.join("Cargo.toml")).is_ok_and(|manifest| manifest.lines().any(|line| line.trim() == "[workspace]")))
+        .map(Path::to_path_buf)
+        .ok_or_else(|| format!("No Cargo workspace at or above {} (use --workspace)", current.display()))
+}
+// Files of the workspace, like inputs stored next to the sources of days
+fn input_path(workspace: &Path, day: u8) -> PathBuf {
+    workspace.join(format!("day{}", day)).join("input.txt")
+}
+fn read_input(options: &Options, day: u8) -> Result<String,String> {
+    let path = options.input.clone().unwrap_or_else(|| input_path(&options.workspace, day));
+    fs::read_to_string(&path).map_err(|err| format!("Couldn't read input {}: {}", path.display(), err))
+}
+
+// Execution
+// Whether all parts succeeded without wrong answers
+fn run(options: &Options) -> Result<bool,String> {
+    let mut answers = Answers::load(&options.answers)?;
+    let mut success = true;
+    for day in &options.days {
+        let registered = find_day(*day)?;
+        let input = match read_input(options, *day) {
+            Ok(input) => input,
+            Err(err) => {
+                eprintln!("Day {}: {}", day, err);
+                success = false;
+                continue;
+            }
+        };
+        for (part, solver) in registered.parts.iter().enumerate().map(|(index, solver)| (index + 1, solver)) {
+            if options.part.is_some_and(|selected| selected != part) {
+                continue;
+            }
+            let start = Instant::now();
+            let answer = solver(&input);
+            let elapsed = start.elapsed();
+            let verdict = answer.as_ref().ok().map(|answer| answers.verdict(*day, part, answer));
+            options.format.print(&PartResult {
+                day: *day,
+                part,
+                answer: &answer,
+                elapsed,
+                verdict: verdict.as_ref()
+            });
+            match (&answer, verdict) {
+                (Err(_), _) | (_, Some(Verdict::WRONG(_))) => success = false,
+                (Ok(answer), Some(Verdict::NEW)) if options.record => answers.record(*day, part, answer),
+                _ => ()
+            }
+        }
+    }
+    if options.record {
+        answers.save(&options.answers)?;
+    }
+    Ok(success)
+}
+// Whether all parts succeeded without regressions against the baseline
+fn bench(options: &Options) -> Result<bool,String> {
+    let mut baseline = Baseline::load(&options.baseline)?;
+    let mut success = true;
+    for day in &options.days {
+        let registered = find_day(*day)?;
+        let input = match read_input(options, *day) {
+            Ok(input) => input,
+            Err(err) => {
+                eprintln!("Day {}: {}", day, err);
+                success = false;
+                continue;
+            }
+        };
+        for (part, solver) in registered.parts.iter().enumerate().map(|(index, solver)| (index + 1, solver)) {
+            if options.part.is_some_and(|selected| selected != part) {
+                continue;
+            }
+            let statistics = match measure(*solver, &input, options.runs) {
+                Ok(statistics) => statistics,
+                Err(err) => {
+                    eprintln!("Day {}, part {}: {}", day, part, err);
+                    success = false;
+                    continue;
+                }
+            };
+            let comparison = match (baseline.get(*day, part), baseline.regression(*day, part, statistics.median, options.threshold)) {
+                (None, _) => String::new(),
+                (Some(median), Some(change)) => {
+                    success = false;
+                    format!(" [baseline {}, {:+.1}%, REGRESSION]", format_duration(median), change)
+                }
+                (Some(median), None) => format!(" [baseline {}, {:+.1}%]", format_duration(median), relative_change(median, statistics.median))
+            };
+            println!("Day {}, part {}: {}{}", day, part, statistics, comparison);
+            if options.save_baseline {
+                baseline.set(*day, part, statistics.median);
+            }
+        }
+    }
+    if options.save_baseline {
+        baseline.save(&options.baseline)?;
+    }
+    Ok(success)
+}
+// Whether all missing inputs got downloaded
+fn fetch_inputs(options: &Options) -> Result<bool,String> {
+    let mut session = None;
+    let mut success = true;
+    for day in &options.days {
+        find_day(*day)?;
+        let path = input_path(&options.workspace, *day);
+        if is_cached(&path) {
+            println!("Day {}: cached at {}", day, path.display());
+            continue;
+        }
+        if session.is_none() {
+            session = Some(read_session(&options.session)?);
+        }
+        match fetch(*day, &path, &options.url, session.as_deref().unwrap_or_default()) {
+            Ok(_) => println!("Day {}: fetched to {}", day, path.display()),
+            Err(err) => {
+                eprintln!("Day {}: {}", day, err);
+                success = false;
+            }
+        }
+    }
+    Ok(success)
+}
+// New day crates, registered in the runner by the next build
+fn new_days(options: &Options) -> Result<bool,String> {
+    if options.all {
+        return Err("New days need their numbers, --all days exist already".to_string());
+    }
+    for day in &options.days {
+        for file in scaffold(&options.workspace, *day)? {
+            println!("Created {}", file.display());
+        }
+        println!("Day {} registered, rebuild the runner to solve it", day);
+    }
+    Ok(true)
+}
+// Whether Rust and Kotlin implementations agree
+fn compare(options: &Options) -> Result<bool,String> {
+    if options.days.len() > 1 && options.jar.is_some() {
+        return Err("--jar needs a single day".to_string());
+    }
+    let mut success = true;
+    for day in &options.days {
+        let outputs = read_input(options, *day).and_then(|input| Ok((
+            rust_output(&options.workspace, *day, &input)?,
+            kotlin_output(&options.workspace, *day, &options.jar, &input)?
+        )));
+        let (rust, kotlin) = match outputs {
+            Ok((rust, kotlin)) => (normalize(&rust), normalize(&kotlin)),
+            Err(err) => {
+                eprintln!("Day {}: {}", day, err);
+                success = false;
+                continue;
+            }
+        };
+        let differences = differences(&rust, &kotlin);
+        if differences.is_empty() {
+            println!("Day {}: Rust and Kotlin agree", day);
+            continue;
+        }
+        success = false;
+        println!("Day {}: DISAGREEMENT", day);
+        for (line, rust, kotlin) in differences {
+            println!("  line {}: Rust {:?}, Kotlin {:?}", line, rust.unwrap_or("<missing>"), kotlin.unwrap_or("<missing>"));
+        }
+    }
+    Ok(success)
+}
+
+// Pseudocode of Intcode programs
+fn decompile_programs(options: &Options) -> Result<bool,String> {
+    for day in &options.days {
+        let intcode = read_input(options, *day).and_then(|input| parse_intcode(*day, &input))?;
+        print!("{}", decompile(&intcode));
+    }
+    Ok(true)
+}
+// Static report and report of a run with the given input values
+fn program_stats(options: &Options) -> Result<bool,String> {
+    for day in &options.days {
+        let intcode = read_input(options, *day).and_then(|input| parse_intcode(*day, &input))?;
+        let dynamic = DynamicStats::measure(intcode.clone(), options.values.clone());
+        println!("Day {}\n{}\n{}", day, StaticStats::analyze(&intcode), dynamic);
+    }
+    Ok(true)
+}
+// Memory of a run with the given input values, stopped by halt, error or breakpoint
+fn dump_memory(options: &Options) -> Result<bool,String> {
+    for day in &options.days {
+        let intcode = read_input(options, *day).and_then(|input| parse_intcode(*day, &input))?;
+        let mut processor = Processor::new(intcode, options.values.clone());
+        let stop = loop {
+            let result = match options.until {
+                Some(breakpoint) => processor.process_until(breakpoint),
+                None => processor.process().map(Some)
+            };
+            match result {
+                Ok(Some(IntcodeResult::OUTPUT(_))) => (),
+                Ok(Some(IntcodeResult::HALT)) => break "halt".to_string(),
+                Ok(None) => break format!("breakpoint {}", processor.position()),
+                Err(err) => break format!("error '{}'", err)
+            }
+        };
+        println!("# Day {}, stopped by {}\n{}", day, stop, MemoryDump::capture(&processor));
+    }
+    Ok(true)
+}
+
+// Runner of all days
+fn main() {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+    let result = match args.first().map(String::as_str) {
+        Some("run") => parse_options(&args[1..]).and_then(|options| run(&options)),
+        Some("bench") => parse_options(&args[1..]).and_then(|options| bench(&options)),
+        Some("fetch") => parse_options(&args[1..]).and_then(|options| fetch_inputs(&options)),
+        Some("new") => parse_options(&args[1..]).and_then(|options| new_days(&options)),
+        Some("compare") => parse_options(&args[1..]).and_then(|options| compare(&options)),
+        Some("decompile") => parse_options(&args[1..]).and_then(|options| decompile_programs(&options)),
+        Some("stats") => parse_options(&args[1..]).and_then(|options| program_stats(&options)),
+        Some("dump") => parse_options(&args[1..]).and_then(|options| dump_memory(&options)),
+        _ => Err(USAGE.to_string())
+    };
+    match result {
+        Ok(true) => (),
+        Ok(false) => process::exit(1),
+        Err(err) => {
+            eprintln!("{}", err);
+            process::exit(2);
+        }
+    }
+}
@@ -0,0 +1,114 @@
+// Imports
+use std::{
+    str::FromStr,
+    time::Duration
+};
+use crate::answers::Verdict;
+
+// Outcome of one part
+pub struct PartResult<'a> {
+    pub day: u8,
+    pub part: usize,
+    pub answer: &'a Result<String,String>,
+    pub elapsed: Duration,
+    pub verdict: Option<&'a Verdict>
+}
+
+// Output format, human-readable text or JSON lines
+#[derive(Debug,Clone,Copy,Eq,PartialEq)]
+pub enum Format {
+    TEXT,
+    JSON
+}
+impl FromStr for Format {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Self::TEXT),
+            "json" => Ok(Self::JSON),
+            _ => Err(format!("Invalid output format: {}", s))
+        }
+    }
+}
+impl Format {
+    pub fn print(&self, result: &PartResult) {
+        match self {
+            Self::TEXT => print_text(result),
+            Self::JSON => println!("{}", json(result))
+        }
+    }
+}
+
+// Text
+pub fn format_duration(duration: Duration) -> String {
+    format!("{:.3} ms", duration.as_secs_f64() * 1000.0)
+}
+fn print_text(result: &PartResult) {
+    let verdict = result.verdict.map_or(String::new(), |verdict| format!(" [{}]", verdict));
+    match result.answer {
+        Ok(answer) if answer.contains('\n') => println!("Day {}, part {}: ({}){}\n{}", result.day, result.part, format_duration(result.elapsed), verdict, answer),
+        Ok(answer) => println!("Day {}, part {}: {} ({}){}", result.day, result.part, answer, format_duration(result.elapsed), verdict),
+        Err(err) => eprintln!("Day {}, part {}: Failed! {} ({})", result.day, result.part, err, format_duration(result.elapsed))
+    }
+}
+
+// JSON: answers as strings with type 'integer', 'text' or 'error', elapsed milliseconds
+fn json_string(text: &str) -> String {
+    let mut json = String::from("\"");
+    for chr in text.chars() {
+        match chr {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            chr if (chr as u32) < 0x20 => json.push_str(&format!("\\u{:04x}", chr as u32)),
+            chr => json.push(chr)
+        }
+    }
+    json.push('"');
+    json
+}
+fn json(result: &PartResult) -> String {
+    let mut fields = vec![
+        format!("\"day\":{}", result.day),
+        format!("\"part\":{}", result.part)
+    ];
+    match result.answer {
+        Ok(answer) => {
+            fields.push(format!("\"answer\":{}", json_string(answer)));
+            fields.push(format!("\"type\":\"{}\"", if answer.parse::<i128>().is_ok() {"integer"} else {"text"}));
+        }
+        Err(err) => {
+            fields.push("\"answer\":null".to_string());
+            fields.push("\"type\":\"error\"".to_string());
+            fields.push(format!("\"error\":{}", json_string(err)));
+        }
+    }
+    fields.push(format!("\"elapsed\":{:.3}", result.elapsed.as_secs_f64() * 1000.0));
+    match result.verdict {
+        Some(Verdict::CORRECT) => fields.push("\"verdict\":\"correct\"".to_string()),
+        Some(Verdict::WRONG(expected)) => {
+            fields.push("\"verdict\":\"wrong\"".to_string());
+            fields.push(format!("\"expected\":{}", json_string(expected)));
+        }
+        Some(Verdict::NEW) => fields.push("\"verdict\":\"new\"".to_string()),
+        None => ()
+    }
+    format!("{{{}}}", fields.join(","))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_strings() {
+        assert_eq!(json_string("plain 42"), r#""plain 42""#);
+        assert_eq!(json_string(r#"say "hi""#), r#""say \"hi\"""#);
+        assert_eq!(json_string(r"C:\day\8"), r#""C:\\day\\8""#);
+        assert_eq!(json_string("# #\n ##\r\n\t"), r##""# #\n ##\r\n\t""##);
+        assert_eq!(json_string("\u{0}\u{1b}[0m\u{1f}"), r#""\u0000\u001b[0m\u001f""#);
+        assert_eq!(json_string("Größe → 🎄"), "\"Größe → 🎄\"");
+    }
+}
@@ -0,0 +1,11 @@
+// Imports
+use intcode::loader::parse_program;
+
+// Days with an Intcode program as puzzle input
+const INTCODE_DAYS: &[u8] = &[2, 5, 7, 9];
+pub fn parse_intcode(day: u8, input: &str) -> Result<Vec<isize>,String> {
+    if !INTCODE_DAYS.contains(&day) {
+        return Err(format!("Day {} has no Intcode program!", day));
+    }
+    Ok(parse_program(input)?)
+}
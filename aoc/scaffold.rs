@@ -0,0 +1,200 @@
+// Imports
+use std::{
+    fs,
+    path::{Path,PathBuf}
+};
+
+// New day crate, '{day}' replaced by number
+const CARGO_TEMPLATE: &str = r#"[package]
+name = "day{day}"
+version = "0.1.0"
+authors = ["Christoph 'Youka' Spanknebel"]
+edition = "2018"
+license = "MIT"
+
+[lib]
+path = "lib.rs"
+
+[[bin]]
+name = "day{day}"
+path = "main.rs"
+
+[dependencies]
+parsing = { path = "../parsing" }
+"#;
+const LIB_TEMPLATE: &str = r#"// Input
+pub fn parse_input(input: &str) -> Result<Vec<String>,String> {
+    Ok(parsing::parse_lines(input)?)
+}
+
+// Parts
+pub fn part1(_lines: &[String]) -> Result<usize,String> {
+    Err("Day {day} not solved yet".to_string())
+}
+pub fn part2(_lines: &[String]) -> Result<usize,String> {
+    Err("Day {day} not solved yet".to_string())
+}
+
+// Solvers
+pub fn solve_part1(input: &str) -> Result<String,String> {
+    part1(&parse_input(input)?).map(|answer| answer.to_string())
+}
+pub fn solve_part2(input: &str) -> Result<String,String> {
+    part2(&parse_input(input)?).map(|answer| answer.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Example of the puzzle description
+    const EXAMPLE: &str = "";
+
+    #[test]
+    #[ignore = "example missing"]
+    fn example() {
+        let lines = parse_input(EXAMPLE).expect("Example should be valid!");
+        assert_eq!(part1(&lines), Ok(0));
+        assert_eq!(part2(&lines), Ok(0));
+    }
+}
+"#;
+const MAIN_TEMPLATE: &str = r#"use day{day}::*;
+
+// Day {day}
+fn main() {
+    let lines = parsing::read_stdin()
+        .and_then(|input| parse_input(&input))
+        .unwrap_or_else(|err| panic!("Input invalid! {}", err));
+    println!("Part 1: {}", part1(&lines).unwrap_or_else(|err| panic!("{}", err)));
+    println!("Part 2: {}", part2(&lines).unwrap_or_else(|err| panic!("{}", err)));
+}
+"#;
+
+// Day crate in workspace, registered as workspace member, runner dependency and solver; created files
+pub fn scaffold(workspace: &Path, day: u8) -> Result<Vec<PathBuf>,String> {
+    let name = format!("day{}", day);
+    let directory = workspace.join(&name);
+    if directory.exists() {
+        return Err(format!("{} exists already!", directory.display()));
+    }
+    // Registration edits first, so unexpected manifests leave nothing behind
+    let members = workspace.join("Cargo.toml");
+    let dependencies = workspace.join("aoc").join("Cargo.toml");
+    let solvers = workspace.join("aoc").join("solvers.rs");
+    let (manifest, runner_manifest, registry) = (read(&members)?, read(&dependencies)?, read(&solvers)?);
+    let registrations = [
+        (&members, &manifest, insert_before_end(&manifest, "members = [", "\n]", &format!(",\n    \"{}\"", name))?),
+        (&dependencies, &runner_manifest, insert_dependency(&runner_manifest, &format!("{} = {{ path = \"../{}\" }}", name, name))?),
+        (&solvers, &registry, insert_before_end(&registry, "pub const DAYS", "\n];", &format!(",\n    Day {{day: {}, parts: [{}::solve_part1, {}::solve_part2]}}", day, name, name))?)
+    ];
+    // Day files and registrations, undone on failure
+    fs::create_dir(&directory).map_err(|err| format!("Couldn't create {}: {}", directory.display(), err))?;
+    let result = write_day(&directory, day).and_then(|files| {
+        for (path, _, edited) in &registrations {
+            write(path, edited)?;
+        }
+        Ok(files)
+    });
+    if result.is_err() {
+        let _ = fs::remove_dir_all(&directory);
+        for (path, original, _) in &registrations {
+            let _ = write(path, original);
+        }
+    }
+    result
+}
+fn write_day(directory: &Path, day: u8) -> Result<Vec<PathBuf>,String> {
+    [
+        ("Cargo.toml", CARGO_TEMPLATE),
+        ("lib.rs", LIB_TEMPLATE),
+        ("main.rs", MAIN_TEMPLATE),
+        ("input.txt", "")
+    ].iter()
+        .map(|(file, template)| {
+            let path = directory.join(file);
+            write(&path, &template.replace("{day}", &day.to_string())).map(|_| path)
+        })
+        .collect()
+}
+
+// Text insertion before end of a list
+fn insert_before_end(text: &str, start: &str, end: &str, insertion: &str) -> Result<String,String> {
+    let start = text.find(start).ok_or(format!("Missing '{}'!", start))?;
+    let end = start + text[start..].find(end).ok_or(format!("Missing '{}' after list start!", end.trim()))?;
+    Ok(format!("{}{}{}", &text[..end], insertion, &text[end..]))
+}
+// Line at end of the dependencies section
+fn insert_dependency(text: &str, dependency: &str) -> Result<String,String> {
+    let start = text.find("[dependencies]").ok_or("Missing '[dependencies]'!")?;
+    let end = text[start..].find("\n[").map_or(text.len(), |end| start + end);
+    let end = start + text[start..end].trim_end().len();
+    Ok(format!("{}\n{}{}", &text[..end], dependency, &text[end..]))
+}
+fn read(path: &Path) -> Result<String,String> {
+    fs::read_to_string(path).map_err(|err| format!("Couldn't read {}: {}", path.display(), err))
+}
+fn write(path: &Path, text: &str) -> Result<(),String> {
+    fs::write(path, text).map_err(|err| format!("Couldn't write {}: {}", path.display(), err))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{env,process::{self,Command}};
+
+    // Workspace with runner registrations and a copy of the parsing crate
+    fn temporary_workspace(name: &str) -> PathBuf {
+        let workspace = env::temp_dir().join(format!("aoc-scaffold-{}-{}", name, process::id()));
+        let _ = fs::remove_dir_all(&workspace);
+        fs::create_dir_all(workspace.join("aoc")).expect("Temporary workspace should be creatable!");
+        fs::create_dir_all(workspace.join("parsing")).expect("Temporary workspace should be creatable!");
+        let parsing = Path::new(env!("CARGO_MANIFEST_DIR")).join("..").join("parsing");
+        for file in &["Cargo.toml", "lib.rs"] {
+            fs::copy(parsing.join(file), workspace.join("parsing").join(file)).expect("Parsing crate should be copyable!");
+        }
+        fs::write(workspace.join("Cargo.toml"), "[workspace]\nmembers = [\n    \"parsing\"\n]\nresolver = \"2\"\n").expect("Workspace manifest should be writable!");
+        fs::write(workspace.join("aoc/Cargo.toml"), "[dependencies]\nday1 = { path = \"../day1\" }\n\n[features]\njit = []\n").expect("Runner manifest should be writable!");
+        fs::write(workspace.join("aoc/solvers.rs"), "pub const DAYS: &[Day] = &[\n    Day {day: 1, parts: [day1::solve_part1, day1::solve_part2]}\n];\n").expect("Solvers should be writable!");
+        workspace
+    }
+
+    #[test]
+    fn registers_new_day() {
+        let workspace = temporary_workspace("register");
+        let files = scaffold(&workspace, 11).expect("Day 11 should be new!");
+        assert_eq!(files.len(), 4);
+        assert_eq!(fs::read_to_string(workspace.join("day11/input.txt")).ok().as_deref(), Some(""));
+        assert!(fs::read_to_string(workspace.join("day11/main.rs")).is_ok_and(|main| main.starts_with("use day11::*;")));
+        assert_eq!(fs::read_to_string(workspace.join("Cargo.toml")).ok().as_deref(), Some("[workspace]\nmembers = [\n    \"parsing\",\n    \"day11\"\n]\nresolver = \"2\"\n"));
+        assert_eq!(fs::read_to_string(workspace.join("aoc/Cargo.toml")).ok().as_deref(), Some("[dependencies]\nday1 = { path = \"../day1\" }\nday11 = { path = \"../day11\" }\n\n[features]\njit = []\n"));
+        assert!(fs::read_to_string(workspace.join("aoc/solvers.rs")).is_ok_and(|solvers| solvers.contains("day1::solve_part2]},\n    Day {day: 11, parts: [day11::solve_part1, day11::solve_part2]}\n];")));
+        assert!(scaffold(&workspace, 11).is_err());
+        fs::remove_dir_all(&workspace).expect("Temporary workspace should be removable!");
+    }
+
+    #[test]
+    fn unexpected_manifest_leaves_nothing() {
+        let workspace = temporary_workspace("unexpected");
+        fs::write(workspace.join("aoc/solvers.rs"), "pub fn find_day() {}\n").expect("Solvers should be writable!");
+        assert_eq!(scaffold(&workspace, 11), Err("Missing 'pub const DAYS'!".to_string()));
+        assert!(!workspace.join("day11").exists());
+        assert_eq!(fs::read_to_string(workspace.join("Cargo.toml")).ok().as_deref(), Some("[workspace]\nmembers = [\n    \"parsing\"\n]\nresolver = \"2\"\n"));
+        fs::remove_dir_all(&workspace).expect("Temporary workspace should be removable!");
+    }
+
+    #[test]
+    fn new_day_compiles() {
+        let workspace = temporary_workspace("compile");
+        scaffold(&workspace, 11).expect("Day 11 should be new!");
+        // Workspace of the new day and its library dependency, runner files are stubs
+        let status = Command::new(option_env!("CARGO").unwrap_or("cargo"))
+            .args(["build", "--quiet", "--offline", "--all-targets", "--package", "day11"])
+            .current_dir(&workspace)
+            .env("CARGO_TARGET_DIR", workspace.join("target"))
+            .status()
+            .expect("Cargo should start!");
+        assert!(status.success(), "Scaffolded day doesn't compile!");
+        fs::remove_dir_all(&workspace).expect("Temporary workspace should be removable!");
+    }
+}
@@ -0,0 +1,23 @@
+// Solver of one part: puzzle input to answer
+pub type Solver = fn(&str) -> Result<String,String>;
+
+// Registered days
+pub struct Day {
+    pub day: u8,
+    pub parts: [Solver; 2]
+}
+pub const DAYS: &[Day] = &[
+    Day {day: 1, parts: [day1::solve_part1, day1::solve_part2]},
+    Day {day: 2, parts: [day2::solve_part1, day2::solve_part2]},
+    Day {day: 3, parts: [day3::solve_part1, day3::solve_part2]},
+    Day {day: 4, parts: [day4::solve_part1, day4::solve_part2]},
+    Day {day: 5, parts: [day5::solve_part1, day5::solve_part2]},
+    Day {day: 6, parts: [day6::solve_part1, day6::solve_part2]},
+    Day {day: 7, parts: [day7::solve_part1, day7::solve_part2]},
+    Day {day: 8, parts: [day8::solve_part1, day8::solve_part2]},
+    Day {day: 9, parts: [day9::solve_part1, day9::solve_part2]},
+    Day {day: 10, parts: [day10::solve_part1, day10::solve_part2]}
+];
+pub fn find_day(day: u8) -> Result<&'static Day,String> {
+    DAYS.iter().find(|registered| registered.day == day).ok_or(format!("Day {} isn't registered!", day))
+}
@@ -0,0 +1,48 @@
+// Fuel
+pub fn module_fuel_required(mass: usize) -> usize {
+    (mass / 3).saturating_sub(2)
+}
+pub fn module_fuel_required_recursive(mass: usize) -> usize {
+    let fuel = module_fuel_required(mass);
+    if fuel == 0 {0} else {fuel + module_fuel_required_recursive(fuel)}
+}
+
+// Input
+pub fn parse_input(input: &str) -> Result<Vec<usize>,String> {
+    Ok(parsing::parse_lines(input)?)
+}
+
+// Parts
+pub fn part1(masses: &[usize]) -> usize {
+    masses.iter().map(|mass| module_fuel_required(*mass)).sum()
+}
+pub fn part2(masses: &[usize]) -> usize {
+    masses.iter().map(|mass| module_fuel_required_recursive(*mass)).sum()
+}
+
+// Solvers
+pub fn solve_part1(input: &str) -> Result<String,String> {
+    Ok(part1(&parse_input(input)?).to_string())
+}
+pub fn solve_part2(input: &str) -> Result<String,String> {
+    Ok(part2(&parse_input(input)?).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fuel() {
+        assert_eq!(module_fuel_required(12), 2);
+        assert_eq!(module_fuel_required(14), 2);
+        assert_eq!(module_fuel_required(1969), 654);
+        assert_eq!(module_fuel_required(100756), 33583);
+    }
+    #[test]
+    fn fuel_recursive() {
+        assert_eq!(module_fuel_required_recursive(14), 2);
+        assert_eq!(module_fuel_required_recursive(1969), 966);
+        assert_eq!(module_fuel_required_recursive(100756), 50346);
+    }
+}
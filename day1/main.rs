@@ -1,25 +1,11 @@
-fn module_fuel_required(mass: usize) -> usize {
-    (mass / 3).saturating_sub(2)
-}
-
-fn module_fuel_required_recursive(mass: usize) -> usize {
-    let fuel = module_fuel_required(mass);
-    if fuel == 0 {0} else {fuel + module_fuel_required_recursive(fuel)}
-}
+use day1::*;
 
 fn main() {
-    use std::io::{stdin, BufRead};
-    println!(
-        "Summarized fuel required: {:?}",
-        stdin().lock()
-            .lines()
-            .enumerate()
-            .fold((0, 0), |(sum, sum_recursive), (i, line)| {
-                let mass = line.expect("Text line expected!").parse().expect(&format!("Line {} isn't an unsigned integer!", 1+i));
-                let fuel = module_fuel_required(mass);
-                let fuel_recursive = module_fuel_required_recursive(mass);
-                println!("{:3}: Mass {:7} requires ({:6},{:6}) fuel", 1+i, mass, fuel, fuel_recursive);
-                (sum + fuel, sum_recursive + fuel_recursive)
-            })
-    );
-}
\ No newline at end of file
+    let masses = parsing::read_stdin()
+        .and_then(|input| parse_input(&input))
+        .unwrap_or_else(|err| panic!("Input invalid! {}", err));
+    for (i, mass) in masses.iter().enumerate() {
+        println!("{:3}: Mass {:7} requires ({:6},{:6}) fuel", 1+i, mass, module_fuel_required(*mass), module_fuel_required_recursive(*mass));
+    }
+    println!("Summarized fuel required: {:?}", (part1(&masses), part2(&masses)));
+}
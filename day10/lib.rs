@@ -0,0 +1,130 @@
+// Helpers
+pub type Point = (u16,u16);
+fn angle_along_points(p1: Point, p2: Point) -> f32 {
+    (p2.0 as f32 - p1.0 as f32).atan2(p1.1 as f32 - p2.1 as f32)
+}
+fn distance_between_points(p1: Point, p2: Point) -> f32 {
+    (p2.0 as f32 - p1.0 as f32).hypot(p2.1 as f32 - p1.1 as f32)
+}
+fn point_on_line(line: (Point, Point), point: Point) -> bool {
+    angle_along_points(line.0, point) == angle_along_points(line.0, line.1) &&
+    distance_between_points(line.0, point) <= distance_between_points(line.0, line.1)
+}
+
+// Input: asteroid positions of a map
+pub fn parse_input(input: &str) -> Result<Vec<Point>,String> {
+    Ok(
+        parsing::parse_grid(input, "#.")?
+            .positions('#')
+            .into_iter()
+            .map(|(column, row)| (column as u16, row as u16))
+            .collect()
+    )
+}
+
+// Parts
+pub fn part1(asteroids: &[Point]) -> Option<(&Point,u32)> {
+    // Find observables per asteroid
+    asteroids.iter().map(|asteroid|
+        (
+            asteroid,
+            // Check possible observings
+            asteroids.iter().filter(|observable| *observable != asteroid ).fold(0, |mut amount, observable| {
+                // Any blockade between asteroid and possible observable?
+                if !asteroids.iter().filter(|blockade| *blockade != observable && *blockade != asteroid ).any(|blockade| point_on_line((*asteroid, *observable), *blockade) ) {
+                    amount += 1;
+                }
+                amount
+            })
+        )
+    )
+    // Find asteroid with most observings
+    .max_by(|o1, o2| o1.1.cmp(&o2.1) )
+}
+// Targets in order of being hit by a laser rotating clockwise from up, hitting one per angle and rotation
+pub fn vaporization_order(asteroids: &[Point], observer: &Point) -> Vec<Point> {
+    use std::{
+        cmp::Ordering,
+        f32::consts::PI
+    };
+    // Get all targets by coordinate, angle and distance
+    let mut targets = asteroids.iter()
+        .filter(|asteroid| *asteroid != observer )
+        .map(|asteroid| (
+            asteroid,
+            {
+                let mut angle = angle_along_points(*observer, *asteroid);
+                if angle < 0.0 {
+                    angle += 2.0 * PI;
+                }
+                angle
+            },
+            distance_between_points(*observer, *asteroid)
+        ))
+        .collect::<Vec<_>>();
+    // Sort targets clockwise first, distance second
+    targets.sort_by(|t1, t2| {
+        let cmp_angles = t1.1.partial_cmp(&t2.1).unwrap();
+        match cmp_angles {
+            Ordering::Equal => t1.2.partial_cmp(&t2.2).unwrap(),
+            _ => cmp_angles
+        }
+    });
+    // Rotate until all targets got hit
+    let mut hit = vec![false; targets.len()];
+    let mut order = Vec::with_capacity(targets.len());
+    while order.len() < targets.len() {
+        let mut angle = -1.0;
+        for (index, target) in targets.iter().enumerate() {
+            if !hit[index] && target.1 != angle {
+                hit[index] = true;
+                order.push(*target.0);
+                angle = target.1;
+            }
+        }
+    }
+    order
+}
+// 200th target hit, none with less targets
+pub fn part2(asteroids: &[Point], observer: &Point) -> Option<u16> {
+    vaporization_order(asteroids, observer).get(199).map(|target| target.0 * 100 + target.1)
+}
+
+// Solvers
+pub fn solve_part1(input: &str) -> Result<String,String> {
+    part1(&parse_input(input)?).map(|(_, visible)| visible.to_string()).ok_or("No asteroids!".to_string())
+}
+pub fn solve_part2(input: &str) -> Result<String,String> {
+    let asteroids = parse_input(input)?;
+    let (observer, _) = part1(&asteroids).ok_or("No asteroids!")?;
+    part2(&asteroids, observer).map(|hit| hit.to_string()).ok_or("Less than 200 asteroids hit!".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn best_location() {
+        for (map, location, visible) in &[
+            (".#..#\n.....\n#####\n....#\n...##", (3,4), 8),
+            ("......#.#.\n#..#.#....\n..#######.\n.#.#.###..\n.#..#.....\n..#....#.#\n#..#....#.\n.##.#..###\n##...#..#.\n.#....####", (5,8), 33),
+            ("#.#...#.#.\n.###....#.\n.#....#...\n##.#.#.#.#\n....#.#.#.\n.##..###.#\n..#...##..\n..##....##\n......#...\n.####.###.", (1,2), 35),
+            (".#..#..###\n####.###.#\n....###.#.\n..###.##.#\n##.##.#.#.\n....###..#\n..#.#..#.#\n#..#.#.###\n.##...##.#\n.....#.#..", (6,3), 41)
+        ] {
+            assert_eq!(part1(&parse_input(map).expect("Example should be valid!")), Some((location, *visible)));
+        }
+    }
+    #[test]
+    fn vaporization() {
+        let asteroids = parse_input(".#....#####...#..\n##...##.#####..##\n##...#...#.#####.\n..#.....#...###..\n..#.#.....#....##").expect("Example should be valid!");
+        assert_eq!(vaporization_order(&asteroids, &(8,3)), [
+            (8,1), (9,0), (9,1), (10,0), (9,2), (11,1), (12,1), (11,2), (15,1),
+            (12,2), (13,2), (14,2), (15,2), (12,3), (16,4), (15,4), (10,4), (4,4),
+            (2,4), (2,3), (0,2), (1,2), (0,1), (1,1), (5,2), (1,0), (5,1),
+            (6,1), (6,0), (7,0), (8,0), (10,1), (14,0), (16,1), (13,3), (14,3)
+        ]);
+        // Less than 200 targets
+        assert_eq!(part2(&asteroids, &(8,3)), None);
+    }
+}
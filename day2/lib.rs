@@ -0,0 +1,40 @@
+use intcode::{*, search::*};
+
+// Intcode processing
+fn process_intcode(mut intcode: Vec<isize>, noun: isize, verb: isize) -> Processor {
+    // Insert "noun" and "verb"
+    *intcode.get_mut(1).expect("Intcode must have at least 2 elements!") = noun;
+    *intcode.get_mut(2).expect("Intcode must have at least 3 elements!") = verb;
+    Processor::new(intcode, vec![])
+}
+
+// Input
+pub fn parse_input(input: &str) -> Result<Vec<isize>,String> {
+    Ok(loader::parse_program(input)?)
+}
+
+// Parts
+pub fn part1(intcode: &[isize]) -> Result<isize,String> {
+    let mut processor = process_intcode(intcode.to_vec(), 12, 2);
+    run_to_halt(&mut processor).map_err(|err| format!("Intcode processing failed! {}", err))?;
+    processor.memory().first().copied().ok_or("Intcode shouldn't be empty!".to_string())
+}
+// Noun & verb
+pub fn part2(intcode: &[isize]) -> Option<(isize,isize)> {
+    let nouns_verbs = (0..=99).flat_map(|noun| (0..=99).map(move |verb| (noun, verb)) );
+    search_first(
+        nouns_verbs,
+        available_threads(),
+        |(noun, verb)| process_intcode(intcode.to_vec(), *noun, *verb),
+        |_, processor, _| processor.memory().first().filter(|output| **output == 19690720).map(|_| ())
+    ).map(|(noun_verb, _)| noun_verb)
+}
+
+// Solvers
+pub fn solve_part1(input: &str) -> Result<String,String> {
+    Ok(part1(&parse_input(input)?)?.to_string())
+}
+pub fn solve_part2(input: &str) -> Result<String,String> {
+    let (noun, verb) = part2(&parse_input(input)?).ok_or("No noun and verb found!")?;
+    Ok((100 * noun + verb).to_string())
+}
@@ -0,0 +1,84 @@
+// Imports
+use geometry::*;
+
+// Lines of one wire
+fn wire_lines_distance_to_point(lines: &[Line], point: &Point) -> Option<usize> {
+    let mut distance = 0;
+    for line in lines {
+        if let Some(point_distance) = line.point_inner_distance(point) {
+            return Some(distance + point_distance);
+        }
+        distance += line.mov.size();
+    }
+    None
+}
+// Crossings of wires apart from origin
+fn wire_intersections(wire1_lines: &[Line], wire2_lines: &[Line]) -> Vec<Point> {
+    wire1_lines.iter()
+        .flat_map(|wire1_line| wire2_lines.iter().filter_map(move |wire2_line| wire1_line.intersect(wire2_line)))
+        .filter(|point| point.distance() != 0)
+        .collect()
+}
+
+// Input: two wires of moves
+pub type Wires = (Vec<Line>,Vec<Line>);
+pub fn parse_input(input: &str) -> Result<Wires,String> {
+    match parsing::parse_lists::<Move>(input, ',')?.as_slice() {
+        [wire1, wire2] => Ok((lines_from_moves(wire1), lines_from_moves(wire2))),
+        wires => Err(format!("Two wires expected from input, got {}!", wires.len()))
+    }
+}
+
+// Parts
+// Nearest wire intersection to origin
+pub fn part1(wires: &Wires) -> Option<usize> {
+    wire_intersections(&wires.0, &wires.1).iter().map(Point::distance).min()
+}
+// Shortest (& combined) way of intersecting wires from origin
+pub fn part2(wires: &Wires) -> Option<usize> {
+    wire_intersections(&wires.0, &wires.1).iter()
+        .map(|point|
+            wire_lines_distance_to_point(&wires.0, point).expect("Point should be on wire 1!") +
+            wire_lines_distance_to_point(&wires.1, point).expect("Point should be on wire 2!")
+        )
+        .min()
+}
+
+// Solvers
+pub fn solve_part1(input: &str) -> Result<String,String> {
+    part1(&parse_input(input)?).map(|distance| distance.to_string()).ok_or("Wires don't intersect!".to_string())
+}
+pub fn solve_part2(input: &str) -> Result<String,String> {
+    part2(&parse_input(input)?).map(|distance| distance.to_string()).ok_or("Wires don't intersect!".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        for (wires, distance, steps) in &[
+            ("R8,U5,L5,D3\nU7,R6,D4,L4", 6, 30),
+            ("R75,D30,R83,U83,L12,D49,R71,U7,L72\nU62,R66,U55,R34,D71,R55,D58,R83", 159, 610),
+            ("R98,U47,R26,D63,R33,U87,L62,D20,R33,U53,R51\nU98,R91,D20,R16,D67,R40,U7,R15,U6,R7", 135, 410)
+        ] {
+            let wires = parse_input(wires).expect("Example should be valid!");
+            assert_eq!(part1(&wires), Some(*distance));
+            assert_eq!(part2(&wires), Some(*steps));
+        }
+    }
+    #[test]
+    fn intersect() {
+        let (wire1, wire2) = parse_input("R8,U5,L5,D3\nU7,R6,D4,L4").expect("Example should be valid!");
+        // Down from (3,5) to (3,2) crosses left from (6,3) to (2,3)
+        assert_eq!(wire1[3].intersect(&wire2[3]), Some(Point(3,3)));
+        assert_eq!(wire2[3].intersect(&wire1[3]), Some(Point(3,3)));
+        assert_eq!(wire1[2].intersect(&wire2[2]), Some(Point(6,5)));
+        // Both from origin
+        assert_eq!(wire1[0].intersect(&wire2[0]), Some(Point(0,0)));
+        // Parallel, orthogonal but apart
+        assert_eq!(wire1[0].intersect(&wire2[1]), None);
+        assert_eq!(wire1[1].intersect(&wire2[1]), None);
+    }
+}
@@ -0,0 +1,82 @@
+// Imports
+use std::{
+    collections::HashMap,
+    ops::RangeInclusive
+};
+
+// Input
+pub fn parse_input(input: &str) -> Result<RangeInclusive<u32>,String> {
+    Ok(parsing::parse_range(input)?)
+}
+
+// Checks
+pub fn has_six_digits(num: u32) -> bool {
+    (100_000..=999_999).contains(&num)
+}
+pub fn has_2_adjacent_digits(digits: &[u8]) -> bool {
+    digits.first().and_then(|first_digit|
+        digits.iter()
+            .skip(1)
+            .try_fold(first_digit, |last_digit, digit| if digit == last_digit {None} else {Some(digit)} )
+            .map_or(Some(()), |_| None )
+    ).is_some()
+}
+pub fn has_increasing_digits(digits: &[u8]) -> bool {
+    digits.first().and_then(|first_digit|
+        digits.iter()
+            .skip(1)
+            .try_fold(first_digit, |last_digit, digit| if digit >= last_digit {Some(digit)} else {None} )
+    ).is_some()
+}
+pub fn has_digit_twice(digits: &[u8]) -> bool {
+    let mut map = HashMap::with_capacity(2);
+    digits.iter().for_each(|digit| *map.entry(digit).or_insert(0) += 1 );
+    map.iter().any(|(_,digit_number)| *digit_number == 2 )
+}
+
+// Parts
+fn hits(range: &RangeInclusive<u32>) -> Vec<Vec<u8>> {
+    range.clone()
+        .filter(|num| has_six_digits(*num) )
+        .map(|num| num.to_string().into_bytes() )
+        .filter(|digits| has_2_adjacent_digits(digits) && has_increasing_digits(digits) )
+        .collect()
+}
+pub fn part1(range: &RangeInclusive<u32>) -> usize {
+    hits(range).len()
+}
+pub fn part2(range: &RangeInclusive<u32>) -> usize {
+    hits(range).iter().filter(|digits| has_digit_twice(digits) ).count()
+}
+
+// Solvers
+pub fn solve_part1(input: &str) -> Result<String,String> {
+    Ok(part1(&parse_input(input)?).to_string())
+}
+pub fn solve_part2(input: &str) -> Result<String,String> {
+    Ok(part2(&parse_input(input)?).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits(num: u32) -> Vec<u8> {
+        num.to_string().into_bytes()
+    }
+
+    #[test]
+    fn password_rules() {
+        for (num, adjacent, increasing) in &[(111111, true, true), (223450, true, false), (123789, false, true)] {
+            assert!(has_six_digits(*num));
+            assert_eq!(has_2_adjacent_digits(&digits(*num)), *adjacent);
+            assert_eq!(has_increasing_digits(&digits(*num)), *increasing);
+        }
+    }
+    #[test]
+    fn digit_twice() {
+        assert!(has_digit_twice(&digits(112233)));
+        assert!(!has_digit_twice(&digits(123444)));
+        assert!(has_digit_twice(&digits(111122)));
+    }
+}
@@ -0,0 +1,43 @@
+use intcode::{*, search::*};
+
+// Intcode processing
+pub fn process_intcode(intcode: &[isize], input: isize) -> Result<Vec<isize>,String> {
+    run_to_halt(&mut Processor::new(intcode.to_vec(), vec![input])).map_err(|err| format!("Intcode processing failed! {}", err))
+}
+// Coverage of a run with failed tests before the diagnostic code, showing which parts ran
+pub fn diagnose(intcode: &[isize], input: isize) -> Result<Option<String>,String> {
+    let mut processor = Processor::new(intcode.to_vec(), vec![input]);
+    processor.enable_coverage();
+    let output = run_to_halt(&mut processor).map_err(|err| format!("Intcode processing failed! {}", err))?;
+    Ok(
+        if output.iter().rev().skip(1).any(|test| *test != 0) {
+            Some(processor.coverage().expect("Coverage should be enabled!").report(intcode))
+        } else {
+            None
+        }
+    )
+}
+
+// Input
+pub fn parse_input(input: &str) -> Result<Vec<isize>,String> {
+    Ok(loader::parse_program(input)?)
+}
+
+// Parts: test outputs & diagnostic code
+pub fn part1(intcode: &[isize]) -> Result<Vec<isize>,String> {
+    process_intcode(intcode, 1)
+}
+pub fn part2(intcode: &[isize]) -> Result<Vec<isize>,String> {
+    process_intcode(intcode, 5)
+}
+
+// Solvers
+fn diagnostic_code(output: Vec<isize>) -> Result<String,String> {
+    output.last().map(|code| code.to_string()).ok_or("No diagnostic code!".to_string())
+}
+pub fn solve_part1(input: &str) -> Result<String,String> {
+    diagnostic_code(part1(&parse_input(input)?)?)
+}
+pub fn solve_part2(input: &str) -> Result<String,String> {
+    diagnostic_code(part2(&parse_input(input)?)?)
+}
@@ -0,0 +1,75 @@
+// Orbit
+#[derive(Debug,Eq,PartialEq)]
+pub struct Orbit {
+    pub center_object: String,
+    pub border_object: String
+}
+
+// Input: orbits 'center)border' by line
+pub fn parse_input(input: &str) -> Result<Vec<Orbit>,String> {
+    Ok(
+        parsing::parse_pairs(input, ')')?
+            .into_iter()
+            .map(|(center_object, border_object)| Orbit {center_object, border_object})
+            .collect()
+    )
+}
+
+// Traverse orbits
+fn count_indirect_and_direct_orbits(orbits: &[Orbit], current: &Orbit, depth: usize) -> usize {
+    orbits.iter().fold(depth+1, |mut sum, orbit| {
+        if current.border_object == orbit.center_object {
+            sum += count_indirect_and_direct_orbits(orbits, orbit, depth+1);
+        }
+        sum
+    })
+}
+fn find_lowest_orbits_to_santa(orbits: &[Orbit], current: &Orbit, previous: Option<&Orbit>, depth: usize) -> Option<usize> {
+    if current.border_object == "SAN" {
+        Some(depth)
+    } else {
+        orbits.iter().fold(None, |mut found, orbit| {
+            if Some(orbit) != previous && (current.border_object == orbit.center_object || current.center_object == orbit.border_object) {
+                if let Some(possible_found) = find_lowest_orbits_to_santa(orbits, orbit, Some(current), depth+1) {
+                    found = found.map(|f| f.min(possible_found)).or(Some(possible_found));
+                }
+            }
+            found
+        })
+    }
+}
+
+// Parts
+pub fn part1(orbits: &[Orbit]) -> Result<usize,String> {
+    let center_of_mass = orbits.iter().find(|orbit| orbit.center_object == "COM" ).ok_or("No center-of-mass?")?;
+    Ok(count_indirect_and_direct_orbits(orbits, center_of_mass, 0))
+}
+pub fn part2(orbits: &[Orbit]) -> Result<Option<usize>,String> {
+    let own_position = orbits.iter().find(|orbit| orbit.border_object == "YOU" ).ok_or("No own position?")?;
+    Ok(find_lowest_orbits_to_santa(orbits, own_position, None, 0).map(|way| way-2 ))
+}
+
+// Solvers
+pub fn solve_part1(input: &str) -> Result<String,String> {
+    Ok(part1(&parse_input(input)?)?.to_string())
+}
+pub fn solve_part2(input: &str) -> Result<String,String> {
+    part2(&parse_input(input)?)?.map(|transfers| transfers.to_string()).ok_or("No way to Santa!".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "COM)B\nB)C\nC)D\nD)E\nE)F\nB)G\nG)H\nD)I\nE)J\nJ)K\nK)L";
+
+    #[test]
+    fn orbit_count() {
+        assert_eq!(part1(&parse_input(EXAMPLE).expect("Example should be valid!")), Ok(42));
+    }
+    #[test]
+    fn transfers_to_santa() {
+        let orbits = parse_input(&format!("{}\nK)YOU\nI)SAN", EXAMPLE)).expect("Example should be valid!");
+        assert_eq!(part2(&orbits), Ok(Some(4)));
+    }
+}
@@ -0,0 +1,124 @@
+use std::{
+    cell::Cell,
+    rc::Rc
+};
+use intcode::{*, asynchronous::*, coverage::Coverage, search::*};
+
+// Permutation module
+mod permutation {
+    pub fn permutations_recursive<T>(items: &mut [T], n: usize, results: &mut Vec<Vec<T>>) where T: Clone {
+        match n {
+            0 => results.push( items.to_vec() ),
+            _ => for i in 0..n {
+                items.swap(i, n-1);
+                permutations_recursive(items, n-1, results);
+                items.swap(i, n-1);
+            }
+        }
+    }
+    pub fn all_permutations<T>(mut items: Vec<T>) -> Vec<Vec<T>> where T: Clone {
+        let n = items.len();
+        let mut results = Vec::with_capacity( (1..=n).product() );
+        permutations_recursive(&mut items, n, &mut results);
+        results
+    }
+}
+use permutation::*;
+
+// Input
+pub fn parse_input(input: &str) -> Result<Vec<isize>,String> {
+    Ok(loader::parse_program(input)?)
+}
+
+// Parts
+pub fn part1(intcode: &[isize]) -> Option<isize> {
+    // Try all phase settings
+    find_best(all_permutations( (0..=4).collect() ), available_threads(), |phase_settings| {
+        // Process amplifiers
+        Some(phase_settings.iter().fold(0, |in_out, phase_setting|
+            match Processor::new(intcode.to_vec(), vec![*phase_setting, in_out]).process().unwrap_or_else(|err| panic!("Intcode processing error occured! {}", err)) {
+                IntcodeResult::OUTPUT(out) => out,
+                IntcodeResult::HALT => panic!("Halting too soon!")
+            }
+        ))
+    }).map(|(_, max_output)| max_output )
+}
+pub fn part2(intcode: &[isize]) -> Option<isize> {
+    // Try all phase settings
+    find_best(all_permutations( (5..=9).collect() ), available_threads(), |phase_settings| {
+        // Connect amplifiers in a feedback loop, each one sending to the next
+        let (senders, receivers): (Vec<_>, Vec<_>) = phase_settings.iter().map(|_| channel() ).unzip();
+        senders[0].send(0);
+        let last_output = Rc::new(Cell::new(0));
+        let mut executor = Executor::new();
+        for (index, (phase_setting, receiver)) in phase_settings.iter().zip(receivers).enumerate() {
+            let sender = &senders[(index + 1) % senders.len()];
+            let last_output = last_output.clone();
+            let mut amplifier = AsyncProcessor::new(Processor::new(intcode.to_vec(), vec![*phase_setting]), receiver);
+            executor.spawn(async move {
+                while let Some(out) = amplifier.next().await {
+                    sender.send(out);
+                    last_output.set(out);
+                }
+                if let Some(err) = amplifier.error() {
+                    panic!("Processor error occured! {}", err);
+                }
+            });
+        }
+        executor.run().expect("Amplifiers stalled!");
+        Some(last_output.get())
+    }).map(|(_, max_output)| max_output )
+}
+
+// Coverage of all amplifiers over all phase settings of part 1, showing which parts of the program ran
+pub fn phase_coverage(intcode: &[isize]) -> Result<Coverage,String> {
+    let mut coverage = Coverage::new();
+    for phase_settings in all_permutations( (0..=4).collect() ) {
+        phase_settings.iter().try_fold(0, |in_out, phase_setting| {
+            let mut processor = Processor::new(intcode.to_vec(), vec![*phase_setting, in_out]);
+            processor.enable_coverage();
+            let result = processor.process();
+            coverage.merge(processor.coverage().expect("Coverage should be enabled!"));
+            match result? {
+                IntcodeResult::OUTPUT(out) => Ok(out),
+                IntcodeResult::HALT => Err("Halting too soon!".to_string())
+            }
+        })?;
+    }
+    Ok(coverage)
+}
+
+// Solvers
+pub fn solve_part1(input: &str) -> Result<String,String> {
+    part1(&parse_input(input)?).map(|signal| signal.to_string()).ok_or("No output signal!".to_string())
+}
+pub fn solve_part2(input: &str) -> Result<String,String> {
+    part2(&parse_input(input)?).map(|signal| signal.to_string()).ok_or("No output signal!".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn amplifiers() {
+        for (program, signal) in &[
+            ("3,15,3,16,1002,16,10,16,1,16,15,15,4,15,99,0,0", 43210),
+            ("3,23,3,24,1002,24,10,24,1002,23,-1,23,101,5,23,23,1,24,23,23,4,23,99,0,0", 54321),
+            ("3,31,3,32,1002,32,10,32,1001,31,-2,31,1007,31,0,33,1002,33,7,33,1,33,31,31,1,32,31,31,4,31,99,0,0,0", 65210)
+        ] {
+            assert_eq!(part1(&parse_input(program).expect("Example should be valid!")), Some(*signal));
+        }
+    }
+    #[test]
+    fn feedback_loop() {
+        for (program, signal) in &[
+            ("3,26,1001,26,-4,26,3,27,1002,27,2,27,1,27,26,27,4,27,1001,28,-1,28,1005,28,6,99,0,0,5", 139629729),
+            ("3,52,1001,52,-5,52,3,53,1,52,56,54,1007,54,5,55,1005,55,26,1001,54,\
+             -5,54,1105,1,12,1,53,54,53,1008,54,0,55,1001,55,1,55,2,53,55,53,4,\
+             53,1001,56,-1,56,1005,56,6,99,0,0,0,0,10", 18216)
+        ] {
+            assert_eq!(part2(&parse_input(program).expect("Example should be valid!")), Some(*signal));
+        }
+    }
+}
@@ -0,0 +1,96 @@
+// Image (space format)
+pub type Pixels = Vec<u8>;
+pub struct SpaceImage {
+    width: u16,
+    layers: Vec<Pixels>
+}
+impl SpaceImage {
+    pub fn new_from_bytes(width: u16, height: u16, bytes: &[u8]) -> Result<Self,&str> {
+        let layers = bytes.chunks_exact(width as usize * height as usize);
+        if !layers.remainder().is_empty() {
+            Err("Image bytes doesn't fit in given width * height layers!")
+        } else if bytes.iter().any(|pixel| *pixel > 2 ) {
+            Err("Image bytes have to be in range 0-2!")
+        } else {
+            Ok(Self{
+                width,
+                layers: layers.map(|chunk| chunk.to_vec() ).collect()
+            })
+        }
+    }
+    pub fn width(&self) -> u16 {
+        self.width
+    }
+    pub fn layers(&self) -> &[Pixels] {
+        &self.layers
+    }
+}
+
+// Input: 25x6 image
+pub fn parse_input(input: &str) -> Result<SpaceImage,String> {
+    SpaceImage::new_from_bytes(25, 6, &parsing::parse_digits(input)?).map_err(|err| err.to_string())
+}
+
+// Helpers
+fn count<Item>(items: &[Item], find: &Item) -> usize where Item: Eq {
+    items.iter().filter(|item| *item == find ).count()
+}
+
+// Parts
+pub fn part1(image: &SpaceImage) -> Option<usize> {
+    let low_zero_layer = image.layers().iter()
+        .map(|pixels| (pixels, count(pixels, &0) ))
+        .min_by(|pixels1, pixels2| pixels1.1.cmp(&pixels2.1) )
+        .map(|(pixels, _)| pixels )?;
+    Some( count(low_zero_layer, &1) * count(low_zero_layer, &2) )
+}
+pub fn part2(image: &SpaceImage) -> Option<String> {
+    let mut merged_layers = image.layers().first()?.clone();
+    for layer in image.layers().iter().skip(1) {
+        for (i, pixel) in merged_layers.iter_mut().enumerate() {
+            if *pixel == 2 && layer[i] != 2 {
+                *pixel = layer[i]
+            }
+        }
+    }
+    Some(
+        merged_layers
+        .chunks(image.width() as usize)
+        .map(|row|
+            String::from_utf8(row.iter().map(|pixel|
+                match pixel {
+                    1 => b'#',  // White
+                    _ => b' '   // Black, transparent, everything else
+                }
+            ).collect()).expect("Only ascii expected!")
+        )
+        .collect::<Vec<_>>()
+        .join("\n")
+    )
+}
+
+// Solvers
+pub fn solve_part1(input: &str) -> Result<String,String> {
+    part1(&parse_input(input)?).map(|checksum| checksum.to_string()).ok_or("Image mustn't be empty!".to_string())
+}
+pub fn solve_part2(input: &str) -> Result<String,String> {
+    part2(&parse_input(input)?).ok_or("Image mustn't be empty!".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn merge() {
+        let image = SpaceImage::new_from_bytes(2, 2, &parsing::parse_digits("0222112222120000").expect("Example should be valid!")).expect("Example should fit!");
+        assert_eq!(image.layers().len(), 4);
+        assert_eq!(part2(&image).as_deref(), Some(" #\n# "));
+    }
+    // Example of the statement has digits beyond 2, rejected by images
+    #[test]
+    fn checksum() {
+        let image = SpaceImage::new_from_bytes(3, 2, &[0,1,2,1,1,2, 0,0,1,1,2,2]).expect("Layers should fit!");
+        assert_eq!(part1(&image), Some(6));
+    }
+}
@@ -0,0 +1,28 @@
+use intcode::*;
+
+// Input
+pub fn parse_input(input: &str) -> Result<Vec<isize>,String> {
+    Ok(loader::parse_program(input)?)
+}
+
+// Parts
+fn first_output(intcode: &[isize], mode: isize) -> Result<isize,String> {
+    match Processor::new(intcode.to_vec(), vec![mode]).process()? {
+        IntcodeResult::OUTPUT(out) => Ok(out),
+        IntcodeResult::HALT => Err("Intcode processor halted unexpectly!".to_string())
+    }
+}
+pub fn part1(intcode: &[isize]) -> Result<isize,String> {
+    first_output(intcode, 1)
+}
+pub fn part2(intcode: &[isize]) -> Result<isize,String> {
+    first_output(intcode, 2)
+}
+
+// Solvers
+pub fn solve_part1(input: &str) -> Result<String,String> {
+    part1(&parse_input(input)?).map(|keycode| keycode.to_string())
+}
+pub fn solve_part2(input: &str) -> Result<String,String> {
+    part2(&parse_input(input)?).map(|signal| signal.to_string())
+}
@@ -0,0 +1,48 @@
+// Imports
+use std::str::FromStr;
+
+// Direction
+#[derive(Debug,Clone,Copy,Eq,PartialEq)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right
+}
+impl FromStr for Direction {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "U" => Ok(Self::Up),
+            "D" => Ok(Self::Down),
+            "L" => Ok(Self::Left),
+            "R" => Ok(Self::Right),
+            _ => Err(format!("Invalid direction string: {}", s))
+        }
+    }
+}
+
+// Move
+#[derive(Debug,Clone,Copy,Eq,PartialEq)]
+pub struct Move {
+    pub direction: Direction,
+    pub units: isize
+}
+impl FromStr for Move {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.len() < 2 {
+            return Err(format!("String is too short for a direction + units: {}", s));
+        }
+        let (direction, units) = s.split_at(1);
+        Ok(Move {
+            direction: direction.parse().map_err(|_| format!("Invalid direction for move: {}", direction) )?,
+            units: units.parse().map_err(|_| format!("Invalid units for move: {}", units) )?
+        })
+    }
+}
+impl Move {
+    pub fn size(&self) -> usize {
+        self.units.unsigned_abs()
+    }
+}
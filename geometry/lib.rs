@@ -0,0 +1,8 @@
+// Geometry library
+mod direction;
+mod point;
+mod line;
+
+pub use direction::*;
+pub use point::*;
+pub use line::*;
@@ -0,0 +1,75 @@
+// Imports
+use crate::{
+    direction::{Direction,Move},
+    point::Point
+};
+
+// Line
+#[derive(Debug,Clone,Copy,Eq,PartialEq)]
+pub struct Line {
+    pub point: Point,
+    pub mov: Move
+}
+impl Line {
+    pub fn end_point(&self) -> Point {
+        let mut point = self.point;
+        point.movement(self.mov);
+        point
+    }
+    pub fn intersect(&self, other: &Line) -> Option<Point> {
+        // Find horizontal and vertical line
+        let mut hline = None;
+        let mut vline = None;
+        match self.mov.direction {
+            Direction::Up | Direction::Down => vline = Some(self),
+            Direction::Left | Direction::Right => hline = Some(self)
+        };
+        match other.mov.direction {
+            Direction::Up | Direction::Down => vline = Some(other),
+            Direction::Left | Direction::Right => hline = Some(other)
+        };
+        // Lines orthogonal?
+        if let (Some(hline), Some(vline)) = (hline, vline) {
+            // Lines intersect?
+            let hline_end_point = hline.end_point();
+            let vline_end_point = vline.end_point();
+            let hrange = hline.point.0.min(hline_end_point.0)..=hline.point.0.max(hline_end_point.0);
+            let vrange = vline.point.1.min(vline_end_point.1)..=vline.point.1.max(vline_end_point.1);
+            if hrange.contains(&vline.point.0) && vrange.contains(&hline.point.1) {
+                return Some(Point(vline.point.0, hline.point.1))
+            }
+        }
+        None
+    }
+    pub fn point_inner_distance(&self, point: &Point) -> Option<usize> {
+        match self.mov.direction {
+            Direction::Up | Direction::Down => if point.0 == self.point.0 {
+                let end_point = self.end_point();
+                let vrange = self.point.1.min(end_point.1)..=self.point.1.max(end_point.1);
+                if vrange.contains(&point.1) {
+                    return Some((point.1 - self.point.1).unsigned_abs())
+                }
+            },
+            Direction::Left | Direction::Right => if point.1 == self.point.1 {
+                let end_point = self.end_point();
+                let hrange = self.point.0.min(end_point.0)..=self.point.0.max(end_point.0);
+                if hrange.contains(&point.0) {
+                    return Some((point.0 - self.point.0).unsigned_abs())
+                }
+            }
+        }
+        None
+    }
+}
+
+// Connected lines of moves from origin
+pub fn lines_from_moves(moves: &[Move]) -> Vec<Line> {
+    let mut point = Point(0,0);
+    moves.iter()
+        .map(|&mov| {
+            let line = Line {point, mov};
+            point.movement(mov);
+            line
+        })
+        .collect()
+}
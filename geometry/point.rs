@@ -0,0 +1,20 @@
+// Imports
+use crate::direction::{Direction,Move};
+
+// Point
+#[derive(Debug,Clone,Copy,Eq,PartialEq)]
+pub struct Point(pub isize, pub isize);
+impl Point {
+    pub fn movement(&mut self, mov: Move) {
+        match mov.direction {
+            Direction::Up => self.1 += mov.units,
+            Direction::Down => self.1 -= mov.units,
+            Direction::Left => self.0 -= mov.units,
+            Direction::Right => self.0 += mov.units
+        }
+    }
+    // Manhattan distance to origin
+    pub fn distance(&self) -> usize {
+        self.0.unsigned_abs() + self.1.unsigned_abs()
+    }
+}
@@ -0,0 +1,242 @@
+// Imports
+use std::{
+    cell::RefCell,
+    collections::VecDeque,
+    future::Future,
+    pin::Pin,
+    rc::Rc,
+    sync::{Arc,Mutex},
+    task::{Context,Poll,Wake,Waker}
+};
+use crate::processor::{IntcodeResult,Processor};
+
+// Asynchronous sequence of values
+pub trait Stream {
+    type Item;
+    fn poll_next(&mut self, cx: &mut Context) -> Poll<Option<Self::Item>>;
+    fn next(&mut self) -> Next<'_,Self> where Self: Sized {
+        Next(self)
+    }
+}
+pub struct Next<'a,S>(&'a mut S);
+impl<S> Future for Next<'_,S> where S: Stream {
+    type Output = Option<S::Item>;
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        self.0.poll_next(cx)
+    }
+}
+
+// Single-threaded channel, closed by dropping the sender
+struct ChannelState {
+    values: VecDeque<isize>,
+    closed: bool,
+    waker: Option<Waker>
+}
+impl ChannelState {
+    fn wake(&mut self) {
+        if let Some(waker) = self.waker.take() {
+            waker.wake();
+        }
+    }
+}
+pub struct Sender(Rc<RefCell<ChannelState>>);
+impl Sender {
+    pub fn send(&self, value: isize) {
+        let mut state = self.0.borrow_mut();
+        state.values.push_back(value);
+        state.wake();
+    }
+}
+impl Drop for Sender {
+    fn drop(&mut self) {
+        let mut state = self.0.borrow_mut();
+        state.closed = true;
+        state.wake();
+    }
+}
+pub struct Receiver(Rc<RefCell<ChannelState>>);
+impl Stream for Receiver {
+    type Item = isize;
+    fn poll_next(&mut self, cx: &mut Context) -> Poll<Option<Self::Item>> {
+        let mut state = self.0.borrow_mut();
+        match state.values.pop_front() {
+            Some(value) => Poll::Ready(Some(value)),
+            None if state.closed => Poll::Ready(None),
+            None => {
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+pub fn channel() -> (Sender, Receiver) {
+    let state = Rc::new(RefCell::new(ChannelState {
+        values: VecDeque::new(),
+        closed: false,
+        waker: None
+    }));
+    (Sender(state.clone()), Receiver(state))
+}
+
+// Processor awaiting input from a stream when its queue is empty, outputs as stream (ends on halt or error)
+pub struct AsyncProcessor<S> {
+    processor: Processor,
+    input: S,
+    input_closed: bool,
+    error: Option<String>,
+    done: bool
+}
+impl<S> AsyncProcessor<S> where S: Stream<Item=isize> {
+    pub fn new(processor: Processor, input: S) -> Self {
+        Self {
+            processor,
+            input,
+            input_closed: false,
+            error: None,
+            done: false
+        }
+    }
+    pub fn processor(&self) -> &Processor {
+        &self.processor
+    }
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+}
+impl<S> Stream for AsyncProcessor<S> where S: Stream<Item=isize> {
+    type Item = isize;
+    fn poll_next(&mut self, cx: &mut Context) -> Poll<Option<Self::Item>> {
+        while !self.done {
+            // Wait for input before an input instruction (closed input lets it fail)
+            let awaits_input = self.processor.memory().get(self.processor.position()).is_some_and(|opcode| opcode % 100 == 3);
+            if awaits_input && self.processor.input_mut().is_empty() && !self.input_closed {
+                match self.input.poll_next(cx) {
+                    Poll::Ready(Some(value)) => self.processor.input_mut().push_back(value),
+                    Poll::Ready(None) => self.input_closed = true,
+                    Poll::Pending => return Poll::Pending
+                }
+            }
+            match self.processor.step() {
+                Ok(Some(IntcodeResult::OUTPUT(output))) => return Poll::Ready(Some(output)),
+                Ok(Some(IntcodeResult::HALT)) => self.done = true,
+                Ok(None) => (),
+                Err(err) => {
+                    self.error = Some(err);
+                    self.done = true;
+                }
+            }
+        }
+        Poll::Ready(None)
+    }
+}
+
+// Wakes task by scheduling it again
+struct TaskWaker {
+    task: usize,
+    ready: Arc<Mutex<VecDeque<usize>>>
+}
+impl Wake for TaskWaker {
+    fn wake(self: Arc<Self>) {
+        self.ready.lock().expect("Ready queue poisoned!").push_back(self.task);
+    }
+}
+
+// Single-threaded executor, failing when all unfinished tasks wait on each other
+#[derive(Default)]
+pub struct Executor<'a> {
+    tasks: Vec<Option<Pin<Box<dyn Future<Output=()> + 'a>>>>,
+    ready: Arc<Mutex<VecDeque<usize>>>
+}
+impl<'a> Executor<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn spawn<F>(&mut self, future: F) where F: Future<Output=()> + 'a {
+        self.ready.lock().expect("Ready queue poisoned!").push_back(self.tasks.len());
+        self.tasks.push(Some(Box::pin(future)));
+    }
+    pub fn run(&mut self) -> Result<(),String> {
+        loop {
+            let next = self.ready.lock().expect("Ready queue poisoned!").pop_front();
+            match next {
+                Some(task) => if let Some(future) = &mut self.tasks[task] {
+                    let waker = Waker::from(Arc::new(TaskWaker {
+                        task,
+                        ready: self.ready.clone()
+                    }));
+                    if future.as_mut().poll(&mut Context::from_waker(&waker)).is_ready() {
+                        self.tasks[task] = None;
+                    }
+                }
+                None => {
+                    let pending = self.tasks.iter().filter(|task| task.is_some()).count();
+                    self.tasks.clear();
+                    return match pending {
+                        0 => Ok(()),
+                        _ => Err(format!("Stalled with {} task(s) waiting!", pending))
+                    };
+                }
+            }
+        }
+    }
+}
+
+// Run single future to completion
+pub fn block_on<'a,T,F>(future: F) -> Result<T,String> where T: 'a, F: Future<Output=T> + 'a {
+    let result = Rc::new(RefCell::new(None));
+    let task_result = result.clone();
+    let mut executor = Executor::new();
+    executor.spawn(async move {
+        *task_result.borrow_mut() = Some(future.await);
+    });
+    executor.run()?;
+    let value = result.borrow_mut().take();
+    Ok(value.expect("Finished task should have a result!"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Output of the input
+    const ECHO: &[isize] = &[3,5,4,5,99,0];
+
+    // Two echo processors sending to each other, the first getting a seed value
+    fn echo_loop(seed: Option<isize>) -> (Result<(),String>,Vec<isize>) {
+        let ((sender1, receiver1), (sender2, receiver2)) = (channel(), channel());
+        if let Some(seed) = seed {
+            sender1.send(seed);
+        }
+        let outputs = Rc::new(RefCell::new(vec![]));
+        let mut executor = Executor::new();
+        for (receiver, sender) in [(receiver1, sender2), (receiver2, sender1)] {
+            let outputs = outputs.clone();
+            let mut processor = AsyncProcessor::new(Processor::new(ECHO.to_vec(), vec![]), receiver);
+            executor.spawn(async move {
+                while let Some(output) = processor.next().await {
+                    outputs.borrow_mut().push(output);
+                    sender.send(output);
+                }
+            });
+        }
+        let result = executor.run();
+        let outputs = outputs.borrow().clone();
+        (result, outputs)
+    }
+
+    #[test]
+    fn stall_detection() {
+        assert_eq!(echo_loop(Some(7)), (Ok(()), vec![7, 7]));
+        assert_eq!(echo_loop(None), (Err("Stalled with 2 task(s) waiting!".to_string()), vec![]));
+    }
+    #[test]
+    fn closed_input() {
+        let (sender, receiver) = channel();
+        drop(sender);
+        let mut processor = AsyncProcessor::new(Processor::new(ECHO.to_vec(), vec![]), receiver);
+        assert_eq!(block_on(async move {
+            let output = processor.next().await;
+            (output, processor.error().map(str::to_string))
+        }), Ok((None, Some("Input is missing!".to_string()))));
+    }
+}
@@ -0,0 +1,115 @@
+// Imports
+use std::collections::BTreeMap;
+use crate::decode::*;
+
+// Executed instructions and branch directions
+#[derive(Debug,Clone,Default,Eq,PartialEq)]
+pub struct Coverage {
+    instructions: BTreeMap<usize,usize>,
+    branches: BTreeMap<usize,(usize,usize)>
+}
+impl Coverage {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Recording
+    pub fn record_instruction(&mut self, address: usize) {
+        *self.instructions.entry(address).or_insert(0) += 1;
+    }
+    pub fn record_branch(&mut self, address: usize, taken: bool) {
+        let branch = self.branches.entry(address).or_insert((0, 0));
+        if taken {
+            branch.0 += 1;
+        } else {
+            branch.1 += 1;
+        }
+    }
+    pub fn merge(&mut self, other: &Coverage) {
+        for (address, hits) in &other.instructions {
+            *self.instructions.entry(*address).or_insert(0) += hits;
+        }
+        for (address, (taken, not_taken)) in &other.branches {
+            let branch = self.branches.entry(*address).or_insert((0, 0));
+            branch.0 += taken;
+            branch.1 += not_taken;
+        }
+    }
+
+    // Queries
+    pub fn hits(&self, address: usize) -> usize {
+        self.instructions.get(&address).copied().unwrap_or(0)
+    }
+    pub fn branch(&self, address: usize) -> Option<(usize,usize)> {
+        self.branches.get(&address).copied()
+    }
+
+    // Disassembly listing annotated by hits and branch directions
+    pub fn report(&self, intcode: &[isize]) -> String {
+        let instructions = discover_from(intcode, self.instructions.keys().copied().chain(Some(0)).collect());
+        let mut report = String::new();
+        for (address, line) in listing(intcode, &instructions) {
+            let hits = self.hits(address);
+            let annotation = match instructions.get(&address) {
+                Some(instruction) if is_branch(instruction) => {
+                    let (taken, not_taken) = self.branch(address).unwrap_or((0, 0));
+                    format!(
+                        "  [taken {}, not taken {}]{}",
+                        taken, not_taken,
+                        if hits > 0 && (taken == 0 || not_taken == 0) {" partial"} else {""}
+                    )
+                }
+                _ => String::new()
+            };
+            let counter = match (instructions.contains_key(&address), hits) {
+                (false, _) => String::new(),
+                (true, 0) => "-".to_string(),
+                (true, hits) => hits.to_string()
+            };
+            report.push_str(&format!("{:>8} | {}{}\n", counter, line, annotation));
+        }
+        // Summary
+        let executed = instructions.keys().filter(|address| self.hits(**address) > 0).count();
+        let branches = instructions.values().filter(|instruction| is_branch(instruction)).collect::<Vec<_>>();
+        let directions_covered = branches.iter()
+            .map(|instruction| self.branch(instruction.address).map_or(0, |(taken, not_taken)| (taken > 0) as usize + (not_taken > 0) as usize))
+            .sum::<usize>();
+        report.push_str(&format!(
+            "Instructions: {}/{} executed, branch directions: {}/{} covered\n",
+            executed, instructions.len(), directions_covered, 2 * branches.len()
+        ));
+        report
+    }
+}
+
+// Jumps with both directions possible
+fn is_branch(instruction: &Instruction) -> bool {
+    matches!(instruction.operation, Operation::JUMPTRUE | Operation::JUMPFALSE) && instruction.jump_always().is_none()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::processor::Processor;
+
+    // Input, jump by it to one of two outputs
+    const BRANCH: &[isize] = &[3,11,1005,11,8,104,0,99,104,1,99,0];
+
+    fn run(input: isize) -> Coverage {
+        let mut processor = Processor::new(BRANCH.to_vec(), vec![input]);
+        processor.enable_coverage();
+        processor.by_ref().for_each(drop);
+        processor.coverage().expect("Coverage should be enabled!").clone()
+    }
+
+    #[test]
+    fn merge_runs() {
+        let (mut coverage, other) = (run(0), run(1));
+        assert_eq!((coverage.hits(5), coverage.hits(8), coverage.branch(2)), (1, 0, Some((0, 1))));
+        assert!(coverage.report(BRANCH).ends_with("Instructions: 4/6 executed, branch directions: 1/2 covered\n"));
+        coverage.merge(&other);
+        assert_eq!((coverage.hits(0), coverage.hits(2), coverage.hits(5), coverage.hits(8)), (2, 2, 1, 1));
+        assert_eq!(coverage.branch(2), Some((1, 1)));
+        assert!(coverage.report(BRANCH).ends_with("Instructions: 6/6 executed, branch directions: 2/2 covered\n"));
+    }
+}
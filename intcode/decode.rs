@@ -0,0 +1,248 @@
+// Imports
+use std::{
+    collections::{BTreeMap,BTreeSet},
+    convert::TryFrom,
+    fmt
+};
+
+// Parameter mode
+#[derive(Debug,Clone,Copy,Eq,PartialEq)]
+pub enum ParameterMode {
+    POSITION,
+    IMMEDIATE,
+    RELATIVE
+}
+impl TryFrom<(isize, u8)> for ParameterMode {
+    type Error = String;
+    fn try_from(modes_pos: (isize, u8)) -> Result<Self, Self::Error> {
+        match modes_pos.0 / (10_isize.pow(modes_pos.1 as u32)) % 10 {
+            0 => Ok(Self::POSITION),
+            1 => Ok(Self::IMMEDIATE),
+            2 => Ok(Self::RELATIVE),
+            _ => Err( format!("Invalid parameter mode at {} in {}!", modes_pos.1, modes_pos.0) )
+        }
+    }
+}
+
+// Operation
+#[derive(Debug,Clone,Copy,Eq,PartialEq)]
+pub enum Operation {
+    ADD,
+    MULTIPLY,
+    INPUT,
+    OUTPUT,
+    JUMPTRUE,
+    JUMPFALSE,
+    LESSTHAN,
+    EQUALS,
+    RELBASE,
+    HALT
+}
+impl TryFrom<isize> for Operation {
+    type Error = String;
+    fn try_from(opcode: isize) -> Result<Self, Self::Error> {
+        match opcode % 100 {
+            1 => Ok(Self::ADD),
+            2 => Ok(Self::MULTIPLY),
+            3 => Ok(Self::INPUT),
+            4 => Ok(Self::OUTPUT),
+            5 => Ok(Self::JUMPTRUE),
+            6 => Ok(Self::JUMPFALSE),
+            7 => Ok(Self::LESSTHAN),
+            8 => Ok(Self::EQUALS),
+            9 => Ok(Self::RELBASE),
+            99 => Ok(Self::HALT),
+            _ => Err( format!("Invalid operation code {}!", opcode) )
+        }
+    }
+}
+impl Operation {
+    pub fn parameters(&self) -> usize {
+        match self {
+            Self::ADD | Self::MULTIPLY | Self::LESSTHAN | Self::EQUALS => 3,
+            Self::JUMPTRUE | Self::JUMPFALSE => 2,
+            Self::INPUT | Self::OUTPUT | Self::RELBASE => 1,
+            Self::HALT => 0
+        }
+    }
+    pub fn writes(&self) -> bool {
+        matches!(self, Self::ADD | Self::MULTIPLY | Self::LESSTHAN | Self::EQUALS | Self::INPUT)
+    }
+    pub fn mnemonic(&self) -> &'static str {
+        match self {
+            Self::ADD => "add",
+            Self::MULTIPLY => "mul",
+            Self::INPUT => "in",
+            Self::OUTPUT => "out",
+            Self::JUMPTRUE => "jnz",
+            Self::JUMPFALSE => "jz",
+            Self::LESSTHAN => "lt",
+            Self::EQUALS => "eq",
+            Self::RELBASE => "rbo",
+            Self::HALT => "hlt"
+        }
+    }
+}
+
+// Parameter
+#[derive(Debug,Clone,Copy,Eq,PartialEq)]
+pub struct Parameter {
+    pub mode: ParameterMode,
+    pub value: isize
+}
+impl fmt::Display for Parameter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.mode {
+            ParameterMode::POSITION => write!(f, "[{}]", self.value),
+            ParameterMode::IMMEDIATE => write!(f, "{}", self.value),
+            ParameterMode::RELATIVE => write!(f, "[rb{:+}]", self.value)
+        }
+    }
+}
+
+// Instruction
+#[derive(Debug,Clone,Eq,PartialEq)]
+pub struct Instruction {
+    pub address: usize,
+    pub opcode: isize,
+    pub operation: Operation,
+    pub parameters: Vec<Parameter>
+}
+impl Instruction {
+    pub fn decode(intcode: &[isize], address: usize) -> Result<Self,String> {
+        let opcode = *intcode.get(address).ok_or(format!("Instruction at {} out of intcode!", address))?;
+        let operation = Operation::try_from(opcode).map_err(|err| format!("{} (at {})", err, address) )?;
+        let parameters = (0..operation.parameters())
+            .map(|i| Ok(Parameter {
+                mode: ParameterMode::try_from((opcode / 100, i as u8))?,
+                value: intcode.get(address + 1 + i).copied().unwrap_or(0)
+            }))
+            .collect::<Result<Vec<_>,String>>()?;
+        if operation.writes() && parameters.last().is_some_and(|param| param.mode == ParameterMode::IMMEDIATE) {
+            return Err(format!("Immediate write target in instruction at {}!", address));
+        }
+        Ok(Self {
+            address,
+            opcode,
+            operation,
+            parameters
+        })
+    }
+    pub fn size(&self) -> usize {
+        1 + self.parameters.len()
+    }
+    pub fn next(&self) -> usize {
+        self.address + self.size()
+    }
+    // Jump target, known only for immediate parameters
+    pub fn jump_target(&self) -> Option<usize> {
+        match self.operation {
+            Operation::JUMPTRUE | Operation::JUMPFALSE if self.parameters[1].mode == ParameterMode::IMMEDIATE && self.parameters[1].value >= 0 =>
+                Some(self.parameters[1].value as usize),
+            _ => None
+        }
+    }
+    // Condition of a jump, known only for immediate parameters
+    pub fn jump_always(&self) -> Option<bool> {
+        match self.operation {
+            Operation::JUMPTRUE | Operation::JUMPFALSE if self.parameters[0].mode == ParameterMode::IMMEDIATE =>
+                Some((self.parameters[0].value != 0) == (self.operation == Operation::JUMPTRUE)),
+            _ => None
+        }
+    }
+    // Possible next addresses without knowledge of memory
+    pub fn successors(&self) -> Vec<usize> {
+        match self.operation {
+            Operation::HALT => vec![],
+            Operation::JUMPTRUE | Operation::JUMPFALSE => {
+                let mut successors = vec![];
+                match self.jump_always() {
+                    Some(true) => (),
+                    _ => successors.push(self.next())
+                }
+                match (self.jump_always(), self.jump_target()) {
+                    (Some(false), _) | (_, None) => (),
+                    (_, Some(target)) => successors.push(target)
+                }
+                successors
+            }
+            _ => vec![self.next()]
+        }
+    }
+}
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.operation.mnemonic())?;
+        for (i, param) in self.parameters.iter().enumerate() {
+            write!(f, "{}{}", if i == 0 {" "} else {", "}, param)?;
+        }
+        Ok(())
+    }
+}
+
+// Find instructions reachable from program start
+pub fn discover(intcode: &[isize]) -> BTreeMap<usize,Instruction> {
+    discover_from(intcode, vec![0])
+}
+pub fn discover_from(intcode: &[isize], entries: Vec<usize>) -> BTreeMap<usize,Instruction> {
+    let mut instructions = BTreeMap::new();
+    let mut pending = entries;
+    let mut visited = BTreeSet::new();
+    while let Some(address) = pending.pop() {
+        if address >= intcode.len() || !visited.insert(address) {
+            continue;
+        }
+        if let Ok(instruction) = Instruction::decode(intcode, address) {
+            pending.extend(instruction.successors());
+            // Calls return behind unconditional jumps with a previously stored return address
+            if instruction.jump_always() == Some(true) && stores_return_address(intcode, &instruction) {
+                pending.push(instruction.next());
+            }
+            instructions.insert(address, instruction);
+        }
+    }
+    instructions
+}
+// Return address stored relative to base by an immediate-only add/multiply right before a jump
+pub fn stores_return_address(intcode: &[isize], jump: &Instruction) -> bool {
+    (1..=jump.address.min(4)).any(|len|
+        match Instruction::decode(intcode, jump.address - len) {
+            Ok(previous) if previous.next() == jump.address => match previous.operation {
+                Operation::ADD | Operation::MULTIPLY if previous.parameters[..2].iter().all(|param| param.mode == ParameterMode::IMMEDIATE) &&
+                                                        previous.parameters[2].mode == ParameterMode::RELATIVE => {
+                    let (value1, value2) = (previous.parameters[0].value, previous.parameters[1].value);
+                    let value = if previous.operation == Operation::ADD {value1 + value2} else {value1 * value2};
+                    value == jump.next() as isize
+                }
+                _ => false
+            }
+            _ => false
+        }
+    )
+}
+
+// Listing of reachable instructions and data cells
+pub fn disassemble(intcode: &[isize]) -> Vec<(usize,String)> {
+    listing(intcode, &discover(intcode))
+}
+pub fn listing(intcode: &[isize], instructions: &BTreeMap<usize,Instruction>) -> Vec<(usize,String)> {
+    let mut lines = vec![];
+    let mut address = 0;
+    while address < intcode.len() {
+        match instructions.get(&address) {
+            Some(instruction) => {
+                let codes = intcode[address..instruction.next().min(intcode.len())].iter()
+                    .map(|code| code.to_string())
+                    .collect::<Vec<_>>()
+                    .join(",");
+                lines.push((address, format!("{:05}: {:<24} {}", address, codes, instruction)));
+                address = instruction.next();
+            }
+            None => {
+                lines.push((address, format!("{:05}: {:<24} data", address, intcode[address])));
+                address += 1;
+            }
+        }
+    }
+    lines
+}
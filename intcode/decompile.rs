@@ -0,0 +1,346 @@
+// Imports
+use std::collections::{BTreeMap,BTreeSet};
+use crate::decode::*;
+
+// Analysis of a whole program
+struct Program<'a> {
+    intcode: &'a [isize],
+    code: BTreeMap<usize,Instruction>,
+    code_cells: BTreeSet<usize>,
+    calls: BTreeMap<usize,usize>,
+    return_stores: BTreeSet<usize>
+}
+impl<'a> Program<'a> {
+    fn analyze(intcode: &'a [isize]) -> Self {
+        let code = discover(intcode);
+        let code_cells = code.values()
+            .flat_map(|instruction| instruction.address..instruction.next())
+            .collect();
+        // Calls jump unconditionally after storing the return address through the relative base
+        let mut calls = BTreeMap::new();
+        let mut return_stores = BTreeSet::new();
+        for instruction in code.values() {
+            if let (Some(true), Some(target)) = (instruction.jump_always(), instruction.jump_target()) {
+                if stores_return_address(intcode, instruction) {
+                    calls.insert(instruction.address, target);
+                    if let Some((store_address, _)) = code.range(..instruction.address).next_back().filter(|(_, store)| store.next() == instruction.address) {
+                        return_stores.insert(*store_address);
+                    }
+                }
+            }
+        }
+        Self {
+            intcode,
+            code,
+            code_cells,
+            calls,
+            return_stores
+        }
+    }
+    fn function_entries(&self) -> BTreeSet<usize> {
+        let mut entries = self.calls.values().copied().collect::<BTreeSet<_>>();
+        entries.insert(0);
+        entries
+    }
+    fn function_body(&self, entry: usize) -> Vec<&Instruction> {
+        let mut body = BTreeMap::new();
+        let mut pending = vec![entry];
+        while let Some(address) = pending.pop() {
+            if body.contains_key(&address) {
+                continue;
+            }
+            if let Some(instruction) = self.code.get(&address) {
+                body.insert(address, instruction);
+                if self.calls.contains_key(&address) {
+                    pending.push(instruction.next());
+                } else {
+                    pending.extend(instruction.successors());
+                }
+            }
+        }
+        body.into_values().collect()
+    }
+    fn variables(&self) -> BTreeSet<usize> {
+        self.code.values()
+            .flat_map(|instruction| instruction.parameters.iter())
+            .filter(|param| param.mode == ParameterMode::POSITION && param.value >= 0 && !self.code_cells.contains(&(param.value as usize)))
+            .map(|param| param.value as usize)
+            .collect()
+    }
+    fn function_name(&self, entry: usize) -> String {
+        if entry == 0 {"main".to_string()} else {format!("func_{}", entry)}
+    }
+    fn operand(&self, param: &Parameter) -> String {
+        match param.mode {
+            ParameterMode::IMMEDIATE => param.value.to_string(),
+            ParameterMode::POSITION if param.value >= 0 && self.code_cells.contains(&(param.value as usize)) => format!("mem[{}]", param.value),
+            ParameterMode::POSITION => format!("var_{}", param.value),
+            ParameterMode::RELATIVE => format!("rb[{}]", param.value)
+        }
+    }
+    fn condition(&self, jump: &Instruction, negate: bool) -> String {
+        let operand = self.operand(&jump.parameters[0]);
+        if (jump.operation == Operation::JUMPTRUE) != negate {
+            format!("{} != 0", operand)
+        } else {
+            format!("{} == 0", operand)
+        }
+    }
+    fn statement(&self, instruction: &Instruction) -> String {
+        let params = &instruction.parameters;
+        match instruction.operation {
+            Operation::ADD if params[0] == (Parameter {mode: ParameterMode::IMMEDIATE, value: 0}) =>
+                format!("{} = {};", self.operand(&params[2]), self.operand(&params[1])),
+            Operation::ADD if params[1] == (Parameter {mode: ParameterMode::IMMEDIATE, value: 0}) =>
+                format!("{} = {};", self.operand(&params[2]), self.operand(&params[0])),
+            Operation::MULTIPLY if params[0] == (Parameter {mode: ParameterMode::IMMEDIATE, value: 1}) =>
+                format!("{} = {};", self.operand(&params[2]), self.operand(&params[1])),
+            Operation::MULTIPLY if params[1] == (Parameter {mode: ParameterMode::IMMEDIATE, value: 1}) =>
+                format!("{} = {};", self.operand(&params[2]), self.operand(&params[0])),
+            Operation::ADD | Operation::MULTIPLY | Operation::LESSTHAN | Operation::EQUALS => format!(
+                "{} = {} {} {};",
+                self.operand(&params[2]),
+                self.operand(&params[0]),
+                match instruction.operation {
+                    Operation::ADD => "+",
+                    Operation::MULTIPLY => "*",
+                    Operation::LESSTHAN => "<",
+                    _ => "=="
+                },
+                self.operand(&params[1])
+            ),
+            Operation::INPUT => format!("{} = input();", self.operand(&params[0])),
+            Operation::OUTPUT => format!("output({});", self.operand(&params[0])),
+            Operation::RELBASE if params[0].mode == ParameterMode::IMMEDIATE && params[0].value < 0 => format!("rb -= {};", -params[0].value),
+            Operation::RELBASE => format!("rb += {};", self.operand(&params[0])),
+            Operation::HALT => "halt();".to_string(),
+            Operation::JUMPTRUE | Operation::JUMPFALSE => {
+                if let Some(target) = self.calls.get(&instruction.address) {
+                    return format!("{}();", self.function_name(*target));
+                }
+                let jump = match instruction.jump_target() {
+                    Some(target) => format!("goto label_{};", target),
+                    None if params[1].mode == ParameterMode::RELATIVE => "return;".to_string(),
+                    None => format!("goto *{};", self.operand(&params[1]))
+                };
+                match instruction.jump_always() {
+                    Some(true) => jump,
+                    Some(false) => "; // never jumps".to_string(),
+                    None => format!("if ({}) {}", self.condition(instruction, false), jump)
+                }
+            }
+        }
+    }
+}
+
+// Output line of a function
+struct Line {
+    address: Option<usize>,
+    depth: usize,
+    text: String
+}
+
+// Structured output of one function
+struct FunctionWriter<'a, 'b> {
+    program: &'b Program<'a>,
+    body: Vec<&'b Instruction>,
+    lines: Vec<Line>,
+    labels: BTreeSet<usize>
+}
+impl<'a, 'b> FunctionWriter<'a, 'b> {
+    // Local jump with a static target (no call, no return)
+    fn local_target(&self, index: usize) -> Option<usize> {
+        let instruction = self.body[index];
+        if self.program.calls.contains_key(&instruction.address) || instruction.jump_always() == Some(false) {
+            None
+        } else {
+            instruction.jump_target()
+        }
+    }
+    fn index_of(&self, address: usize) -> Option<usize> {
+        self.body.binary_search_by_key(&address, |instruction| instruction.address).ok()
+    }
+    // All local jumps of a range stay inside or leave to the given exits
+    fn nested(&self, from: usize, to: usize, exits: &[usize]) -> bool {
+        if from >= to {
+            return true;
+        }
+        let (start, end) = (self.body[from].address, self.body[to-1].next());
+        (from..to).all(|index|
+            self.local_target(index).is_none_or(|target| (start..=end).contains(&target) || exits.contains(&target))
+        )
+    }
+    fn push(&mut self, address: Option<usize>, depth: usize, text: String) {
+        self.lines.push(Line {address, depth, text});
+    }
+    fn write_range(&mut self, from: usize, to: usize, depth: usize, scope: Option<(usize,usize)>) {
+        let mut index = from;
+        while index < to {
+            let instruction = self.body[index];
+            // Loop from the farthest back-edge onto this instruction
+            if let Some(back_edge) = (index..to).rev().find(|back_edge| self.local_target(*back_edge) == Some(instruction.address)) {
+                let exit = self.body[back_edge].next();
+                if self.nested(index, back_edge + 1, &scope.map_or(vec![], |(_, outer_exit)| vec![outer_exit])) {
+                    let back_jump = self.body[back_edge];
+                    let unconditional = back_jump.jump_always() == Some(true);
+                    self.push(Some(instruction.address), depth, (if unconditional {"while (true) {"} else {"do {"}).to_string());
+                    self.write_range(index, back_edge, depth + 1, Some((instruction.address, exit)));
+                    let close = if unconditional {
+                        "}".to_string()
+                    } else {
+                        format!("}} while ({});", self.program.condition(back_jump, false))
+                    };
+                    self.push(Some(back_jump.address), depth, close);
+                    index = back_edge + 1;
+                    continue;
+                }
+            }
+            // Conditional skip forward
+            if let (Some(target), None) = (self.local_target(index), instruction.jump_always()) {
+                let end = match self.index_of(target) {
+                    Some(end) if end > index && end <= to => Some(end),
+                    None if to > 0 && target == self.body[to-1].next() && target > instruction.address => Some(to),
+                    _ => None
+                };
+                if let Some(end) = end {
+                    if self.nested(index + 1, end, &scope.map_or(vec![], |(_, exit)| vec![exit])) {
+                        self.push(Some(instruction.address), depth, format!("if ({}) {{", self.program.condition(instruction, true)));
+                        self.write_range(index + 1, end, depth + 1, scope);
+                        self.push(None, depth, "}".to_string());
+                        index = end;
+                        continue;
+                    }
+                }
+            }
+            // Loop exits
+            if let (Some(target), Some((header, exit))) = (self.local_target(index), scope) {
+                if target == exit || target == header {
+                    let jump = if target == exit {"break;"} else {"continue;"};
+                    let text = match instruction.jump_always() {
+                        Some(true) => jump.to_string(),
+                        _ => format!("if ({}) {}", self.program.condition(instruction, false), jump)
+                    };
+                    self.push(Some(instruction.address), depth, text);
+                    index += 1;
+                    continue;
+                }
+            }
+            // Plain statement
+            if !self.program.return_stores.contains(&instruction.address) {
+                if let Some(target) = self.local_target(index) {
+                    self.labels.insert(target);
+                }
+                self.push(Some(instruction.address), depth, self.program.statement(instruction));
+            }
+            index += 1;
+        }
+    }
+    fn write(mut self, entry: usize, output: &mut String) {
+        let len = self.body.len();
+        self.write_range(0, len, 1, None);
+        output.push_str(&format!("void {}() {{\n", self.program.function_name(entry)));
+        let mut labeled = BTreeSet::new();
+        for line in &self.lines {
+            if let Some(address) = line.address {
+                if self.labels.contains(&address) && labeled.insert(address) {
+                    output.push_str(&format!("{:13}{}label_{}:\n", "", "    ".repeat(line.depth - 1), address));
+                }
+            }
+            let annotation = line.address.map_or(String::new(), |address| format!("/* {:05} */", address));
+            output.push_str(&format!("{:13}{}{}\n", annotation, "    ".repeat(line.depth), line.text));
+        }
+        output.push_str("}\n");
+    }
+}
+
+// Decompile intcode into C-like pseudocode
+pub fn decompile(intcode: &[isize]) -> String {
+    let program = Program::analyze(intcode);
+    let mut output = String::new();
+    // Data cells
+    let variables = program.variables();
+    if !variables.is_empty() {
+        output.push_str("// Variables\n");
+        for address in variables {
+            output.push_str(&format!("long var_{} = {};\n", address, program.intcode.get(address).copied().unwrap_or(0)));
+        }
+        output.push('\n');
+    }
+    // Functions
+    for entry in program.function_entries() {
+        let body = program.function_body(entry);
+        if body.is_empty() {
+            continue;
+        }
+        output.push_str(&format!("// Function at {}\n", entry));
+        FunctionWriter {
+            program: &program,
+            body,
+            lines: vec![],
+            labels: BTreeSet::new()
+        }.write(entry, &mut output);
+        output.push('\n');
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn loop_of_quine() {
+        assert_eq!(decompile(&[109,1,204,-1,1001,100,1,100,1008,100,16,101,1006,101,0,99]), "\
+// Variables
+long var_100 = 0;
+long var_101 = 0;
+
+// Function at 0
+void main() {
+/* 00000 */      do {
+/* 00000 */          rb += 1;
+/* 00002 */          output(rb[-1]);
+/* 00004 */          var_100 = var_100 + 1;
+/* 00008 */          var_101 = var_100 == 16;
+/* 00012 */      } while (var_101 == 0);
+/* 00015 */      halt();
+}
+
+");
+    }
+    #[test]
+    fn call_and_return() {
+        assert_eq!(decompile(&[109,20,21101,9,0,0,1105,1,11,99,0,104,42,2105,1,0]), "\
+// Function at 0
+void main() {
+/* 00000 */      rb += 20;
+/* 00006 */      func_11();
+/* 00009 */      halt();
+}
+
+// Function at 11
+void func_11() {
+/* 00011 */      output(42);
+/* 00013 */      return;
+}
+
+");
+    }
+    #[test]
+    fn condition() {
+        assert_eq!(decompile(&[3,8,1006,8,7,104,1,99,0]), "\
+// Variables
+long var_8 = 0;
+
+// Function at 0
+void main() {
+/* 00000 */      var_8 = input();
+/* 00002 */      if (var_8 != 0) {
+/* 00005 */          output(1);
+                 }
+/* 00007 */      halt();
+}
+
+");
+    }
+}
@@ -0,0 +1,140 @@
+// Imports
+use std::{
+    io::{stdin,stdout,Read,Write},
+    sync::{Arc,Mutex},
+    time::{Instant,SystemTime,UNIX_EPOCH}
+};
+use crate::extension::Random;
+
+// Handler of a memory range, addressed by offset into the range
+pub trait Device {
+    fn read(&mut self, offset: usize) -> isize;
+    fn write(&mut self, offset: usize, value: isize);
+}
+
+// Shared device, to inspect it after attaching (processors stay sendable)
+impl<D> Device for Arc<Mutex<D>> where D: Device {
+    fn read(&mut self, offset: usize) -> isize {
+        self.lock().expect("Device poisoned!").read(offset)
+    }
+    fn write(&mut self, offset: usize, value: isize) {
+        self.lock().expect("Device poisoned!").write(offset, value)
+    }
+}
+
+// Console: writes print ASCII characters, reads take bytes from standard input (-1 at end)
+pub struct Console;
+impl Device for Console {
+    fn read(&mut self, _offset: usize) -> isize {
+        let mut byte = [0];
+        match stdin().lock().read(&mut byte) {
+            Ok(1) => byte[0] as isize,
+            _ => -1
+        }
+    }
+    fn write(&mut self, _offset: usize, value: isize) {
+        if let Some(chr) = std::char::from_u32(value as u32) {
+            print!("{}", chr);
+            let _ = stdout().flush();
+        }
+    }
+}
+
+// Clock: offset 0 reads milliseconds since attaching, offset 1 seconds since UNIX epoch
+pub struct Clock {
+    start: Instant
+}
+impl Clock {
+    pub fn new() -> Self {
+        Self {
+            start: Instant::now()
+        }
+    }
+}
+impl Default for Clock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl Device for Clock {
+    fn read(&mut self, offset: usize) -> isize {
+        match offset {
+            0 => self.start.elapsed().as_millis() as isize,
+            _ => SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |time| time.as_secs() as isize)
+        }
+    }
+    fn write(&mut self, _offset: usize, _value: isize) {}
+}
+
+// Framebuffer: pixels row by row
+pub struct Framebuffer {
+    width: usize,
+    pixels: Vec<isize>
+}
+impl Framebuffer {
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            pixels: vec![0; width * height]
+        }
+    }
+    pub fn pixels(&self) -> &[isize] {
+        &self.pixels
+    }
+    pub fn render(&self) -> String {
+        self.pixels
+            .chunks(self.width.max(1))
+            .map(|row| row.iter().map(|pixel| if *pixel == 0 {' '} else {'#'}).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+impl Device for Framebuffer {
+    fn read(&mut self, offset: usize) -> isize {
+        self.pixels.get(offset).copied().unwrap_or(0)
+    }
+    fn write(&mut self, offset: usize, value: isize) {
+        if let Some(pixel) = self.pixels.get_mut(offset) {
+            *pixel = value;
+        }
+    }
+}
+
+// Random source: reads give new random numbers, writes reseed
+impl Device for Random {
+    fn read(&mut self, _offset: usize) -> isize {
+        self.next_value()
+    }
+    fn write(&mut self, _offset: usize, value: isize) {
+        *self = Random::new(value as u64);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::processor::Processor;
+
+    #[test]
+    fn memory_mapped() {
+        let framebuffer = Arc::new(Mutex::new(Framebuffer::new(2, 2)));
+        // Write pixels 1 and 3, read pixel 3 back, output a memory cell behind the device range
+        let mut processor = Processor::new(vec![1101,0,7,101,1101,0,1,103,4,103,4,104,99], vec![]);
+        processor.attach_device(100..104, Box::new(framebuffer.clone())).expect("Range should be free!");
+        assert_eq!(processor.by_ref().collect::<Vec<_>>(), [1, 0]);
+        assert_eq!(processor.error(), None);
+        assert_eq!(framebuffer.lock().expect("Device poisoned!").pixels(), [0, 7, 0, 1]);
+        assert_eq!(framebuffer.lock().expect("Device poisoned!").render(), " #\n #");
+        // Device range kept out of memory
+        assert_eq!(processor.memory().len(), 105);
+        assert_eq!(processor.memory()[101], 0);
+    }
+    #[test]
+    fn attach_ranges() {
+        let mut processor = Processor::new(vec![99], vec![]);
+        assert!(processor.attach_device(10..10, Box::new(Clock::new())).is_err());
+        assert!(processor.attach_device(10..20, Box::new(Clock::new())).is_ok());
+        assert_eq!(processor.attach_device(15..25, Box::new(Clock::new())), Err("Device range 15..25 overlaps attached range 10..20!".to_string()));
+        assert!(processor.attach_device(20..25, Box::new(Clock::new())).is_ok());
+    }
+}
@@ -0,0 +1,214 @@
+// Imports
+use std::{
+    collections::BTreeMap,
+    fmt,
+    fs,
+    path::Path,
+    str::FromStr
+};
+use crate::{
+    decode::*,
+    processor::Processor
+};
+
+// Cells per dump row
+const ROW_CELLS: usize = 8;
+
+// Snapshot of processor memory
+#[derive(Debug,Clone,Eq,PartialEq)]
+pub struct MemoryDump {
+    cells: Vec<isize>,
+    position: usize,
+    relative_base: usize
+}
+impl MemoryDump {
+    pub fn capture(processor: &Processor) -> Self {
+        Self {
+            cells: processor.memory().to_vec(),
+            position: processor.position(),
+            relative_base: processor.relative_base()
+        }
+    }
+    pub fn cells(&self) -> &[isize] {
+        &self.cells
+    }
+    // Processor continuing from the snapshot
+    pub fn restore(&self, input: Vec<isize>) -> Processor {
+        let mut processor = Processor::new(self.cells.clone(), input);
+        processor.set_registers(self.position, self.relative_base);
+        processor
+    }
+    pub fn diff<'a>(&'a self, other: &'a MemoryDump) -> MemoryDiff<'a> {
+        MemoryDiff {
+            old: self,
+            new: other
+        }
+    }
+    fn cell_width(&self) -> usize {
+        self.cells.iter().map(|cell| cell.to_string().len()).max().unwrap_or(1)
+    }
+    fn write_row(&self, f: &mut fmt::Formatter, row: usize, width: usize, changed: &dyn Fn(usize) -> bool) -> fmt::Result {
+        let start = row * ROW_CELLS;
+        write!(f, "{:05}:", start)?;
+        for address in start..start + ROW_CELLS {
+            match self.cells.get(address) {
+                Some(cell) => write!(
+                    f, " {}{:>width$}{}",
+                    if address == self.position {'>'} else {' '},
+                    cell,
+                    if changed(address) {'*'} else {' '},
+                    width = width
+                )?,
+                None => write!(f, " {:width$}", "", width = width + 2)?
+            }
+        }
+        Ok(())
+    }
+    // Instructions decoded from code reachable by program start or current position
+    fn write_hints(&self, f: &mut fmt::Formatter, row: usize, instructions: &BTreeMap<usize,Instruction>) -> fmt::Result {
+        let hints = instructions.range(row * ROW_CELLS..(row + 1) * ROW_CELLS)
+            .map(|(address, instruction)| format!("{}: {}", address, instruction))
+            .collect::<Vec<_>>();
+        if !hints.is_empty() {
+            write!(f, "  | {}", hints.join("; "))?;
+        }
+        Ok(())
+    }
+    fn rows(&self) -> usize {
+        self.cells.len().div_ceil(ROW_CELLS)
+    }
+
+    // Files
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(),String> {
+        fs::write(&path, self.to_string()).map_err(|err| format!("Couldn't write dump file {}: {}", path.as_ref().display(), err) )
+    }
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self,String> {
+        fs::read_to_string(&path)
+            .map_err(|err| format!("Couldn't read dump file {}: {}", path.as_ref().display(), err) )?
+            .parse()
+    }
+}
+impl fmt::Display for MemoryDump {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Position {}, relative base {}, {} cells", self.position, self.relative_base, self.cells.len())?;
+        let (width, instructions) = (self.cell_width(), discover_from(&self.cells, vec![0, self.position]));
+        for row in 0..self.rows() {
+            self.write_row(f, row, width, &|_| false)?;
+            self.write_hints(f, row, &instructions)?;
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+// Header and rows as displayed, markers and instruction hints ignored ('#' comments)
+impl FromStr for MemoryDump {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s.lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty() && !line.trim_start().starts_with('#'));
+        let (_, header) = lines.next().ok_or("Missing dump header")?;
+        let registers = header.strip_prefix("Position ")
+            .and_then(|header| header.strip_suffix(" cells"))
+            .map(|header| header.split(", relative base ").flat_map(|values| values.split(", ")).collect::<Vec<_>>())
+            .filter(|registers| registers.len() == 3)
+            .ok_or(format!("Invalid dump header: {}", header))?;
+        let register = |index: usize| registers[index].parse::<usize>().map_err(|_| format!("Invalid dump header: {}", header));
+        let (position, relative_base, length) = (register(0)?, register(1)?, register(2)?);
+        let mut cells = Vec::with_capacity(length);
+        for (i, line) in lines {
+            let row = line.split("  |").next().unwrap_or_default();
+            let (address, row) = row.split_once(':').ok_or(format!("Line {}: Missing row address", i+1))?;
+            if address.trim().parse() != Ok(cells.len()) {
+                return Err(format!("Line {}: Expected row address {}, got {}", i+1, cells.len(), address.trim()));
+            }
+            for cell in row.split_whitespace() {
+                let cell = cell.trim_start_matches('>').trim_end_matches('*');
+                cells.push(cell.parse().map_err(|_| format!("Line {}: Invalid cell: {}", i+1, cell))?);
+            }
+        }
+        if cells.len() != length {
+            return Err(format!("Expected {} cells, got {}", length, cells.len()));
+        }
+        Ok(Self {
+            cells,
+            position,
+            relative_base
+        })
+    }
+}
+
+// Changed cells between two snapshots
+pub struct MemoryDiff<'a> {
+    old: &'a MemoryDump,
+    new: &'a MemoryDump
+}
+impl<'a> MemoryDiff<'a> {
+    pub fn changes(&self) -> Vec<(usize,isize,isize)> {
+        (0..self.old.cells.len().max(self.new.cells.len()))
+            .map(|address| (
+                address,
+                self.old.cells.get(address).copied().unwrap_or(0),
+                self.new.cells.get(address).copied().unwrap_or(0)
+            ))
+            .filter(|(_, old, new)| old != new)
+            .collect()
+    }
+}
+impl<'a> fmt::Display for MemoryDiff<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let changes = self.changes();
+        let changed = |address| changes.binary_search_by_key(&address, |(changed_address, _, _)| *changed_address).is_ok();
+        let width = self.old.cell_width().max(self.new.cell_width());
+        let (old_instructions, new_instructions) = (
+            discover_from(&self.old.cells, vec![0, self.old.position]),
+            discover_from(&self.new.cells, vec![0, self.new.position])
+        );
+        let mut last_row = None;
+        for (address, _, _) in &changes {
+            let row = address / ROW_CELLS;
+            if last_row == Some(row) {
+                continue;
+            }
+            last_row = Some(row);
+            write!(f, "- ")?;
+            self.old.write_row(f, row, width, &changed)?;
+            self.old.write_hints(f, row, &old_instructions)?;
+            write!(f, "\n+ ")?;
+            self.new.write_row(f, row, width, &changed)?;
+            self.new.write_hints(f, row, &new_instructions)?;
+            writeln!(f)?;
+        }
+        writeln!(f, "{} cells changed", changes.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        // Day 9 quine, stopped in the second iteration of its loop
+        let mut processor = Processor::new(vec![109,1,204,-1,1001,100,1,100,1008,100,16,101,1006,101,0,99], vec![]);
+        for _ in 0..2 {
+            while processor.process_until(8).expect("Quine shouldn't fail!").is_some() {}
+        }
+        let dump = MemoryDump::capture(&processor);
+        assert_eq!((dump.position, dump.relative_base, dump.cells.len()), (8, 2, 102));
+        let loaded = dump.to_string().parse::<MemoryDump>().expect("Dump should be loadable!");
+        assert_eq!(loaded, dump);
+        // Same outputs as the original processor
+        let mut restored = loaded.restore(vec![]);
+        assert_eq!((restored.position(), restored.relative_base(), restored.memory()), (processor.position(), processor.relative_base(), processor.memory()));
+        assert_eq!(restored.by_ref().collect::<Vec<_>>(), processor.by_ref().collect::<Vec<_>>());
+        assert_eq!(restored.error(), None);
+    }
+    #[test]
+    fn invalid_dumps() {
+        assert_eq!("".parse::<MemoryDump>(), Err("Missing dump header".to_string()));
+        assert_eq!("Position 0, relative base 0, 2 cells\n00000:  99".parse::<MemoryDump>(), Err("Expected 2 cells, got 1".to_string()));
+        assert_eq!("Position 0, relative base 0, 1 cells\n00008:  99".parse::<MemoryDump>(), Err("Line 2: Expected row address 0, got 00008".to_string()));
+        assert_eq!("Position 0, relative base 0, 1 cells\n00000:  x".parse::<MemoryDump>(), Err("Line 2: Invalid cell: x".to_string()));
+    }
+}
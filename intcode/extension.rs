@@ -0,0 +1,166 @@
+// Imports
+use std::{
+    convert::TryFrom,
+    thread,
+    time::{Duration,SystemTime,UNIX_EPOCH}
+};
+use crate::{
+    decode::ParameterMode,
+    processor::{IntcodeResult,Processor}
+};
+
+// Additional operation, registered on a processor by operation code
+pub trait Extension {
+    // Count of parameters following the operation code
+    fn parameters(&self) -> usize;
+    // Output or halt by result, continues behind parameters unless jumped
+    fn execute(&mut self, context: &mut ExtensionContext) -> Result<Option<IntcodeResult>,String>;
+}
+
+// Processor access for an executing extension
+pub struct ExtensionContext<'a> {
+    processor: &'a mut Processor,
+    param_modes: isize,
+    jump: Option<usize>
+}
+impl<'a> ExtensionContext<'a> {
+    pub(crate) fn new(processor: &'a mut Processor, param_modes: isize) -> Self {
+        Self {
+            processor,
+            param_modes,
+            jump: None
+        }
+    }
+    pub(crate) fn jump_target(&self) -> Option<usize> {
+        self.jump
+    }
+
+    // Parameters by mode
+    pub fn mode(&self, index: usize) -> Result<ParameterMode,String> {
+        ParameterMode::try_from((self.param_modes, index as u8))
+    }
+    pub fn read(&mut self, index: usize) -> Result<isize,String> {
+        let (pos, mode) = (Processor::position(self.processor) + 1 + index, self.mode(index)?);
+        self.processor.get_intcode_unpacked(pos, mode)
+    }
+    pub fn write(&mut self, index: usize, value: isize) -> Result<(),String> {
+        let (pos, mode) = (Processor::position(self.processor) + 1 + index, self.mode(index)?);
+        let target = self.processor.get_intcode_as_pos(pos, mode)?;
+        self.processor.write_intcode(target, value);
+        Ok(())
+    }
+
+    // Memory & control
+    pub fn memory(&mut self, pos: usize) -> isize {
+        self.processor.read_intcode(pos)
+    }
+    pub fn memory_len(&self) -> usize {
+        self.processor.memory().len()
+    }
+    pub fn relative_base(&self) -> usize {
+        self.processor.relative_base()
+    }
+    pub fn jump(&mut self, pos: usize) {
+        self.jump = Some(pos);
+    }
+}
+
+// Opcode 10 example: print memory range (start, length) inside current memory
+pub struct PrintMemory;
+impl Extension for PrintMemory {
+    fn parameters(&self) -> usize {
+        2
+    }
+    fn execute(&mut self, context: &mut ExtensionContext) -> Result<Option<IntcodeResult>,String> {
+        let (start, length) = (context.read(0)?, context.read(1)?);
+        let end = match start.checked_add(length) {
+            Some(end) if start >= 0 && length >= 0 && end as usize <= context.memory_len() => end,
+            _ => return Err(format!("Invalid memory range {} + {} of {} cells!", start, length, context.memory_len()))
+        };
+        let cells = (start as usize..end as usize)
+            .map(|pos| context.memory(pos).to_string())
+            .collect::<Vec<_>>();
+        println!("{:05}: {}", start, cells.join(","));
+        Ok(None)
+    }
+}
+
+// Opcode 11 example: random number (target), by xorshift
+pub struct Random {
+    state: u64
+}
+impl Random {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            state: seed.max(1)
+        }
+    }
+    pub fn from_time() -> Self {
+        Self::new(SystemTime::now().duration_since(UNIX_EPOCH).map_or(1, |time| time.as_nanos() as u64))
+    }
+    pub fn next_value(&mut self) -> isize {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        (self.state >> 1) as isize
+    }
+}
+impl Extension for Random {
+    fn parameters(&self) -> usize {
+        1
+    }
+    fn execute(&mut self, context: &mut ExtensionContext) -> Result<Option<IntcodeResult>,String> {
+        let value = self.next_value();
+        context.write(0, value)?;
+        Ok(None)
+    }
+}
+
+// Opcode 12 example: sleep (milliseconds)
+pub struct Sleep;
+impl Extension for Sleep {
+    fn parameters(&self) -> usize {
+        1
+    }
+    fn execute(&mut self, context: &mut ExtensionContext) -> Result<Option<IntcodeResult>,String> {
+        let millis = context.read(0)?;
+        thread::sleep(Duration::from_millis(millis.max(0) as u64));
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn processor(intcode: Vec<isize>) -> Processor {
+        let mut processor = Processor::new(intcode, vec![]);
+        processor.register_extension(10, Box::new(PrintMemory)).expect("Opcode 10 should be free!");
+        processor.register_extension(11, Box::new(Random::new(42))).expect("Opcode 11 should be free!");
+        processor
+    }
+
+    #[test]
+    fn print_memory_range() {
+        let mut valid = processor(vec![1110,0,4,99]);
+        assert_eq!(valid.by_ref().count(), 0);
+        assert_eq!(valid.error(), None);
+        for (intcode, error) in [
+            (vec![1110,2,3,99], "Invalid memory range 2 + 3 of 4 cells!".to_string()),
+            (vec![1110,-1,1,99], "Invalid memory range -1 + 1 of 4 cells!".to_string()),
+            (vec![1110,1,isize::MAX,99], format!("Invalid memory range 1 + {} of 4 cells!", isize::MAX))
+        ] {
+            let mut invalid = processor(intcode);
+            assert_eq!(invalid.by_ref().count(), 0);
+            assert_eq!(invalid.error(), Some(error.as_str()));
+            assert_eq!(invalid.memory().len(), 4);
+        }
+    }
+    #[test]
+    fn registration_and_random() {
+        let mut processor = processor(vec![11,5,4,5,99,0]);
+        assert_eq!(processor.next(), Some(Random::new(42).next_value()));
+        assert!(processor.register_extension(98, Box::new(PrintMemory)).is_err());
+        assert!(processor.register_extension(100, Box::new(PrintMemory)).is_err());
+    }
+}
@@ -0,0 +1,118 @@
+// Imports
+use std::{
+    fs,
+    path::{Path,PathBuf},
+    str::FromStr
+};
+use crate::{
+    loader::parse_program,
+    processor::Processor
+};
+
+// Expected behaviour of a program, from lines 'program:', 'input:', 'output:' and optional 'memory:' ('#' comments)
+#[derive(Debug,Clone,Default,Eq,PartialEq)]
+pub struct GoldenTest {
+    pub program: Vec<isize>,
+    pub input: Vec<isize>,
+    pub output: Vec<isize>,
+    pub memory: Option<Vec<isize>>
+}
+impl FromStr for GoldenTest {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // Entries by key line, lines without key continue the values
+        let mut entries: Vec<(usize,&str,String)> = vec![];
+        for (i, line) in s.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            match (line.split_once(':'), entries.last_mut()) {
+                (Some((key, values)), _) => entries.push((i+1, key.trim(), values.to_string())),
+                (None, Some(entry)) => {
+                    entry.2.push('\n');
+                    entry.2.push_str(line);
+                }
+                (None, None) => return Err(format!("Line {}: Expected 'key: values'", i+1))
+            }
+        }
+        let (mut test, mut program, mut output) = (Self::default(), false, false);
+        for (line, key, values) in entries {
+            let values = parse_program(&values).map_err(|err| format!("Line {}: {}", line + err.line - 1, err.message))?;
+            match key {
+                "program" => {
+                    test.program = values;
+                    program = true;
+                }
+                "input" => test.input = values,
+                "output" => {
+                    test.output = values;
+                    output = true;
+                }
+                "memory" => test.memory = Some(values),
+                key => return Err(format!("Line {}: Unknown key '{}'", line, key))
+            }
+        }
+        match (program, output) {
+            (false, _) => Err("Missing 'program' line".to_string()),
+            (_, false) => Err("Missing 'output' line".to_string()),
+            _ => Ok(test)
+        }
+    }
+}
+impl GoldenTest {
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self,String> {
+        fs::read_to_string(&path)
+            .map_err(|err| format!("Couldn't read golden test {}: {}", path.as_ref().display(), err) )?
+            .parse()
+    }
+    // Mismatch description on failure
+    pub fn run(&self) -> Result<(),String> {
+        let mut processor = Processor::new(self.program.clone(), self.input.clone());
+        let output = processor.by_ref().collect::<Vec<_>>();
+        if let Some(err) = processor.error() {
+            return Err(format!("Program failed: {}", err));
+        }
+        if output != self.output {
+            return Err(format!("Expected output {:?}, got {:?}", self.output, output));
+        }
+        match &self.memory {
+            Some(memory) if memory[..] != *processor.memory() => Err(format!("Expected memory {:?}, got {:?}", memory, processor.memory())),
+            _ => Ok(())
+        }
+    }
+}
+
+// Runs all '*.golden' files of directory in name order
+pub type GoldenResult = (PathBuf,Result<(),String>);
+pub fn run_directory<P: AsRef<Path>>(directory: P) -> Result<Vec<GoldenResult>,String> {
+    let mut paths = fs::read_dir(&directory)
+        .map_err(|err| format!("Couldn't read golden directory {}: {}", directory.as_ref().display(), err) )?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().is_some_and(|extension| extension == "golden"))
+        .collect::<Vec<_>>();
+    paths.sort();
+    Ok(paths.into_iter()
+        .map(|path| {
+            let result = GoldenTest::load(&path).and_then(|test| test.run());
+            (path, result)
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+    use super::run_directory;
+
+    #[test]
+    fn golden_files() {
+        let directory = Path::new(env!("CARGO_MANIFEST_DIR")).join("golden");
+        let results = run_directory(&directory).expect("Golden directory should be readable!");
+        assert!(!results.is_empty(), "No golden tests in {}", directory.display());
+        let failures = results.iter()
+            .filter_map(|(path, result)| result.as_ref().err().map(|err| format!("{}: {}", path.display(), err)))
+            .collect::<Vec<_>>();
+        assert!(failures.is_empty(), "Golden tests failed:\n{}", failures.join("\n"));
+    }
+}
@@ -0,0 +1,58 @@
+// Imports
+use std::{
+    collections::VecDeque,
+    io::{stdin,BufRead}
+};
+
+// Supplier of processor input, asked whenever the input queue is empty (errors end processing)
+pub trait InputSource {
+    fn next_input(&mut self) -> Result<Option<isize>,String>;
+}
+
+// Queue
+impl InputSource for VecDeque<isize> {
+    fn next_input(&mut self) -> Result<Option<isize>,String> {
+        Ok(self.pop_front())
+    }
+}
+
+// Iterator
+pub struct IterSource<I>(pub I);
+impl<I> InputSource for IterSource<I> where I: Iterator<Item=isize> {
+    fn next_input(&mut self) -> Result<Option<isize>,String> {
+        Ok(self.0.next())
+    }
+}
+
+// Closure
+pub struct FnSource<F>(pub F);
+impl<F> InputSource for FnSource<F> where F: FnMut() -> Option<isize> {
+    fn next_input(&mut self) -> Result<Option<isize>,String> {
+        Ok((self.0)())
+    }
+}
+
+// Standard input, integers separated by commas or whitespace
+#[derive(Default)]
+pub struct StdinSource {
+    pending: VecDeque<isize>
+}
+impl StdinSource {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+impl InputSource for StdinSource {
+    fn next_input(&mut self) -> Result<Option<isize>,String> {
+        while self.pending.is_empty() {
+            let mut line = String::new();
+            if stdin().lock().read_line(&mut line).map_err(|err| format!("Couldn't read standard input: {}", err))? == 0 {
+                return Ok(None);
+            }
+            for token in line.split(|chr: char| chr == ',' || chr.is_whitespace()).filter(|token| !token.is_empty()) {
+                self.pending.push_back(token.parse().map_err(|_| format!("Input token isn't a signed integer: {}", token))?);
+            }
+        }
+        Ok(self.pending.pop_front())
+    }
+}
@@ -0,0 +1,419 @@
+// Native x86-64 code for straight-line Intcode blocks (add, multiply, compare, relative base, ending at jumps)
+#[cfg(not(all(target_arch = "x86_64", target_os = "linux")))]
+compile_error!("The Intcode JIT needs x86-64 Linux!");
+
+// Imports
+use std::{
+    collections::HashMap,
+    ptr
+};
+use crate::{
+    decode::*,
+    processor::Processor
+};
+
+// Linux memory mapping
+const PROT_READ: i32 = 1;
+const PROT_WRITE: i32 = 2;
+const PROT_EXEC: i32 = 4;
+const MAP_PRIVATE: i32 = 2;
+const MAP_ANONYMOUS: i32 = 0x20;
+extern "C" {
+    fn mmap(addr: *mut u8, length: usize, prot: i32, flags: i32, fd: i32, offset: i64) -> *mut u8;
+    fn mprotect(addr: *mut u8, length: usize, prot: i32) -> i32;
+    fn munmap(addr: *mut u8, length: usize) -> i32;
+}
+
+// Executable copy of machine code
+struct ExecutableBuffer {
+    memory: *mut u8,
+    size: usize
+}
+impl ExecutableBuffer {
+    fn new(code: &[u8]) -> Option<Self> {
+        let size = code.len().div_ceil(4096) * 4096;
+        unsafe {
+            let memory = mmap(ptr::null_mut(), size, PROT_READ | PROT_WRITE, MAP_PRIVATE | MAP_ANONYMOUS, -1, 0);
+            if memory as isize == -1 {
+                return None;
+            }
+            ptr::copy_nonoverlapping(code.as_ptr(), memory, code.len());
+            if mprotect(memory, size, PROT_READ | PROT_EXEC) != 0 {
+                munmap(memory, size);
+                return None;
+            }
+            Some(Self {memory, size})
+        }
+    }
+    // Arguments: memory, memory length, state; result: next position
+    fn call(&self, intcode: &mut [isize], state: &mut JitState) -> usize {
+        unsafe {
+            let function: extern "sysv64" fn(*mut isize, usize, *mut JitState) -> usize = std::mem::transmute(self.memory);
+            function(intcode.as_mut_ptr(), intcode.len(), state)
+        }
+    }
+}
+// Mapping owned exclusively and never written after creation
+unsafe impl Send for ExecutableBuffer {}
+impl Drop for ExecutableBuffer {
+    fn drop(&mut self) {
+        unsafe {
+            munmap(self.memory, self.size);
+        }
+    }
+}
+
+// Processor registers shared with machine code
+#[repr(C)]
+struct JitState {
+    relative_base: usize,
+    instructions: usize
+}
+
+// Registers & condition codes
+const RAX: u8 = 0;
+const RCX: u8 = 1;
+const RSI: u8 = 6;
+const RDI: u8 = 7;
+const R8: u8 = 8;
+const R9: u8 = 9;
+const R10: u8 = 10;
+const R11: u8 = 11;
+const CC_B: u8 = 0x2;
+const CC_AE: u8 = 0x3;
+const CC_E: u8 = 0x4;
+const CC_NE: u8 = 0x5;
+const CC_L: u8 = 0xC;
+
+// Machine code writer (rdi = memory, rsi = length, rdx = state, r8 = relative base, r9 = executed instructions)
+#[derive(Default)]
+struct Assembler {
+    code: Vec<u8>,
+    labels: Vec<Option<usize>>,
+    fixups: Vec<(usize,usize)>
+}
+impl Assembler {
+    // Encoding helpers
+    fn rex(&mut self, reg: u8, index: u8, base: u8) {
+        self.code.push(0x48 | (reg >> 3 & 1) << 2 | (index >> 3 & 1) << 1 | (base >> 3 & 1));
+    }
+    fn modrm_rr(&mut self, reg: u8, rm: u8) {
+        self.code.push(0xC0 | (reg & 7) << 3 | (rm & 7));
+    }
+
+    // Labels
+    fn label(&mut self) -> usize {
+        self.labels.push(None);
+        self.labels.len() - 1
+    }
+    fn bind(&mut self, label: usize) {
+        self.labels[label] = Some(self.code.len());
+    }
+    fn rel32(&mut self, label: usize) {
+        self.fixups.push((self.code.len(), label));
+        self.code.extend(&[0; 4]);
+    }
+    fn finish(mut self) -> Vec<u8> {
+        for (pos, label) in &self.fixups {
+            let target = self.labels[*label].expect("Label should be bound!");
+            let rel = target as i32 - (*pos as i32 + 4);
+            self.code[*pos..*pos+4].copy_from_slice(&rel.to_le_bytes());
+        }
+        self.code
+    }
+
+    // Instructions
+    fn mov_imm(&mut self, dst: u8, value: isize) {
+        self.rex(0, 0, dst);
+        self.code.push(0xB8 + (dst & 7));
+        self.code.extend(&(value as i64).to_le_bytes());
+    }
+    fn mov(&mut self, dst: u8, src: u8) {
+        self.rex(src, 0, dst);
+        self.code.push(0x89);
+        self.modrm_rr(src, dst);
+    }
+    fn add(&mut self, dst: u8, src: u8) {
+        self.rex(src, 0, dst);
+        self.code.push(0x01);
+        self.modrm_rr(src, dst);
+    }
+    fn imul(&mut self, dst: u8, src: u8) {
+        self.rex(dst, 0, src);
+        self.code.extend(&[0x0F, 0xAF]);
+        self.modrm_rr(dst, src);
+    }
+    fn cmp(&mut self, left: u8, right: u8) {
+        self.rex(right, 0, left);
+        self.code.push(0x39);
+        self.modrm_rr(right, left);
+    }
+    fn test(&mut self, reg: u8) {
+        self.rex(reg, 0, reg);
+        self.code.push(0x85);
+        self.modrm_rr(reg, reg);
+    }
+    fn inc(&mut self, reg: u8) {
+        self.rex(0, 0, reg);
+        self.code.push(0xFF);
+        self.modrm_rr(0, reg);
+    }
+    // setcc al + movzx dst, al
+    fn set(&mut self, cc: u8, dst: u8) {
+        self.code.extend(&[0x0F, 0x90 | cc, 0xC0]);
+        self.rex(dst, 0, RAX);
+        self.code.extend(&[0x0F, 0xB6]);
+        self.modrm_rr(dst, RAX);
+    }
+    // mov dst, [rdi + index*8]
+    fn load(&mut self, dst: u8, index: u8) {
+        self.rex(dst, index, RDI);
+        self.code.extend(&[0x8B, 0x04 | (dst & 7) << 3, 0xC0 | (index & 7) << 3 | RDI]);
+    }
+    // mov [rdi + index*8], src
+    fn store(&mut self, index: u8, src: u8) {
+        self.rex(src, index, RDI);
+        self.code.extend(&[0x89, 0x04 | (src & 7) << 3, 0xC0 | (index & 7) << 3 | RDI]);
+    }
+    fn jcc(&mut self, cc: u8, label: usize) {
+        self.code.extend(&[0x0F, 0x80 | cc]);
+        self.rel32(label);
+    }
+    fn jmp(&mut self, label: usize) {
+        self.code.push(0xE9);
+        self.rel32(label);
+    }
+    fn prologue(&mut self) {
+        self.code.extend(&[0x4C, 0x8B, 0x02]);    // mov r8, [rdx]
+        self.code.extend(&[0x4D, 0x31, 0xC9]);    // xor r9, r9
+    }
+    fn epilogue(&mut self) {
+        self.code.extend(&[0x4C, 0x89, 0x02]);          // mov [rdx], r8
+        self.code.extend(&[0x4C, 0x01, 0x4A, 0x08]);    // add [rdx+8], r9
+        self.code.push(0xC3);                           // ret
+    }
+
+    // Intcode parameters, leaving to bail label for memory out of bounds
+    fn address(&mut self, param: &Parameter, dst: u8, bail: usize) {
+        self.mov_imm(dst, param.value);
+        if param.mode == ParameterMode::RELATIVE {
+            self.add(dst, R8);
+        }
+        self.cmp(dst, RSI);
+        self.jcc(CC_AE, bail);
+    }
+    fn operand(&mut self, param: &Parameter, dst: u8, bail: usize) {
+        match param.mode {
+            ParameterMode::IMMEDIATE => self.mov_imm(dst, param.value),
+            ParameterMode::POSITION | ParameterMode::RELATIVE => {
+                self.address(param, RAX, bail);
+                self.load(dst, RAX);
+            }
+        }
+    }
+    fn exit(&mut self, position: usize, epilogue: usize) {
+        self.mov_imm(RAX, position as isize);
+        self.jmp(epilogue);
+    }
+}
+
+// Maximal instructions per block
+const BLOCK_INSTRUCTIONS: usize = 64;
+
+// Compiled code with the intcode it was compiled from
+struct Block {
+    cells: Vec<isize>,
+    function: Option<ExecutableBuffer>
+}
+impl Block {
+    fn compile(intcode: &[isize], start: usize) -> Self {
+        // Supported instructions up to (including) a jump
+        let mut instructions = vec![];
+        let mut address = start;
+        while instructions.len() < BLOCK_INSTRUCTIONS {
+            match Instruction::decode(intcode, address) {
+                Ok(instruction) if instruction.next() <= intcode.len() => match instruction.operation {
+                    Operation::ADD | Operation::MULTIPLY | Operation::LESSTHAN | Operation::EQUALS | Operation::RELBASE => {
+                        address = instruction.next();
+                        instructions.push(instruction);
+                    }
+                    Operation::JUMPTRUE | Operation::JUMPFALSE => {
+                        address = instruction.next();
+                        instructions.push(instruction);
+                        break;
+                    }
+                    _ => break
+                }
+                _ => break
+            }
+        }
+        Self {
+            cells: intcode[start..address.max(start+1).min(intcode.len())].to_vec(),
+            function: if instructions.is_empty() {None} else {ExecutableBuffer::new(&Self::assemble(&instructions, start, address))}
+        }
+    }
+    fn assemble(instructions: &[Instruction], start: usize, end: usize) -> Vec<u8> {
+        let mut asm = Assembler::default();
+        let (epilogue, mut bails) = (asm.label(), vec![]);
+        asm.prologue();
+        let body = asm.label();
+        asm.bind(body);
+        for instruction in instructions {
+            let bail = asm.label();
+            bails.push((bail, instruction.address));
+            let params = &instruction.parameters;
+            match instruction.operation {
+                // Calculate into r10, written to address in rcx
+                Operation::ADD | Operation::MULTIPLY | Operation::LESSTHAN | Operation::EQUALS => {
+                    asm.operand(&params[0], R10, bail);
+                    asm.operand(&params[1], R11, bail);
+                    asm.address(&params[2], RCX, bail);
+                    match instruction.operation {
+                        Operation::ADD => asm.add(R10, R11),
+                        Operation::MULTIPLY => asm.imul(R10, R11),
+                        Operation::LESSTHAN => {
+                            asm.cmp(R10, R11);
+                            asm.set(CC_L, R10);
+                        }
+                        _ => {
+                            asm.cmp(R10, R11);
+                            asm.set(CC_E, R10);
+                        }
+                    }
+                    asm.store(RCX, R10);
+                    asm.inc(R9);
+                    // Self-modification leaves the block
+                    let unmodified = asm.label();
+                    asm.mov_imm(RAX, start as isize);
+                    asm.cmp(RCX, RAX);
+                    asm.jcc(CC_B, unmodified);
+                    asm.mov_imm(RAX, end as isize);
+                    asm.cmp(RCX, RAX);
+                    asm.jcc(CC_AE, unmodified);
+                    asm.exit(instruction.next(), epilogue);
+                    asm.bind(unmodified);
+                }
+                Operation::RELBASE => {
+                    asm.operand(&params[0], R10, bail);
+                    asm.add(R8, R10);
+                    asm.inc(R9);
+                }
+                // Jump target only read if taken
+                _ => {
+                    let not_taken = asm.label();
+                    asm.operand(&params[0], R10, bail);
+                    asm.test(R10);
+                    asm.jcc(if instruction.operation == Operation::JUMPTRUE {CC_E} else {CC_NE}, not_taken);
+                    asm.operand(&params[1], R11, bail);
+                    asm.inc(R9);
+                    // Loops back to the block start stay in native code
+                    asm.mov_imm(RAX, start as isize);
+                    asm.cmp(R11, RAX);
+                    asm.jcc(CC_E, body);
+                    asm.mov(RAX, R11);
+                    asm.jmp(epilogue);
+                    asm.bind(not_taken);
+                    asm.inc(R9);
+                }
+            }
+        }
+        asm.exit(end, epilogue);
+        // Interpreter continues at instructions leaving memory bounds
+        for (bail, address) in bails {
+            asm.bind(bail);
+            asm.exit(address, epilogue);
+        }
+        asm.bind(epilogue);
+        asm.epilogue();
+        asm.finish()
+    }
+}
+
+// Interpreted entries of a position before compiling a block there, short runs don't pay for compilation
+const HOT_ENTRIES: u8 = 16;
+
+// Cache of compiled blocks by start position
+#[derive(Default)]
+pub struct Jit {
+    blocks: HashMap<usize,Block>,
+    entries: Vec<u8>
+}
+impl Jit {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    // Next position after running the block at position, none without a compiled block
+    pub(crate) fn execute(&mut self, position: usize, intcode: &mut [isize], relative_base: &mut usize, instructions: &mut usize) -> Option<usize> {
+        if position >= intcode.len() {
+            return None;
+        }
+        if self.entries.len() < intcode.len() {
+            self.entries.resize(intcode.len(), 0);
+        }
+        if self.entries[position] < HOT_ENTRIES {
+            self.entries[position] += 1;
+            return None;
+        }
+        let block = self.blocks.entry(position).or_insert_with(|| Block::compile(intcode, position));
+        // Code changed since compilation?
+        if intcode.get(position..position + block.cells.len()) != Some(&block.cells[..]) {
+            *block = Block::compile(intcode, position);
+        }
+        let mut state = JitState {
+            relative_base: *relative_base,
+            instructions: 0
+        };
+        let next = block.function.as_ref()?.call(intcode, &mut state);
+        *relative_base = state.relative_base;
+        *instructions += state.instructions;
+        Some(next)
+    }
+}
+
+// Compare JIT against interpreter by outputs, error, final memory and instruction count
+pub fn differential(intcode: &[isize], input: &[isize]) -> Result<(),String> {
+    let run = |jit: bool| {
+        let mut processor = Processor::new(intcode.to_vec(), input.to_vec());
+        if jit {
+            processor.enable_jit();
+        }
+        let outputs = processor.by_ref().collect::<Vec<_>>();
+        (outputs, processor.error().map(str::to_string), processor.memory().to_vec(), processor.instructions())
+    };
+    let (interpreted, compiled) = (run(false), run(true));
+    if interpreted.0 != compiled.0 {
+        Err(format!("Outputs differ: interpreter {:?}, JIT {:?}", interpreted.0, compiled.0))
+    } else if interpreted.1 != compiled.1 {
+        Err(format!("Errors differ: interpreter {:?}, JIT {:?}", interpreted.1, compiled.1))
+    } else if interpreted.2 != compiled.2 {
+        let address = interpreted.2.iter().zip(&compiled.2).position(|(cell1, cell2)| cell1 != cell2).unwrap_or(interpreted.2.len().min(compiled.2.len()));
+        Err(format!("Memory differs first at {}", address))
+    } else if interpreted.3 != compiled.3 {
+        Err(format!("Instruction counts differ: interpreter {}, JIT {}", interpreted.3, compiled.3))
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::differential;
+
+    #[test]
+    fn jit_matches_interpreter() {
+        let programs: &[(&[isize], &[isize])] = &[
+            (&[1,9,10,3,2,3,11,0,99,30,40,50], &[]),
+            (&[3,9,8,9,10,9,4,9,99,-1,8], &[8]),
+            (&[3,3,1107,-1,8,3,4,3,99], &[5]),
+            (&[3,12,6,12,15,1,13,14,13,4,13,99,-1,0,1,9], &[0]),
+            (&[109,1,204,-1,1001,100,1,100,1008,100,16,101,1006,101,0,99], &[]),
+            (&[1102,34915192,34915192,7,4,7,99,0], &[]),
+            (&[104,1125899906842624,99], &[]),
+            // Countdown loop with self-modifying add at 4
+            (&[1101,0,1000,20, 1001,20,-1,20, 1001,21,1,21, 1005,20,4, 1101,0,2,5, 4,21,99], &[])
+        ];
+        for (intcode, input) in programs {
+            assert_eq!(differential(intcode, input), Ok(()), "Program {:?}", intcode);
+        }
+    }
+}
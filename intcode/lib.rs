@@ -0,0 +1,22 @@
+// Intcode processing library
+#![allow(clippy::upper_case_acronyms)]
+mod processor;
+pub mod decode;
+pub mod decompile;
+pub mod coverage;
+pub mod dump;
+pub mod replay;
+pub mod search;
+pub mod loader;
+pub mod extension;
+pub mod input;
+pub mod device;
+pub mod wasm;
+pub mod golden;
+pub mod asynchronous;
+pub mod timer;
+pub mod stats;
+#[cfg(feature = "jit")]
+pub mod jit;
+
+pub use processor::*;
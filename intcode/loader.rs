@@ -0,0 +1,54 @@
+// Imports
+use std::{
+    fs,
+    io::{stdin,Read},
+    path::Path
+};
+
+// Location and reason of invalid program source, shared with other inputs
+pub use parsing::ParseError;
+
+// Comma-separated integers with whitespace, newlines and '#' comments
+pub fn parse_program(source: &str) -> Result<Vec<isize>,ParseError> {
+    // Comments cut off, keeping lines and columns of code
+    let code = source.lines()
+        .map(|line| line.split('#').next().unwrap_or_default())
+        .collect::<Vec<_>>()
+        .join("\n");
+    parsing::parse_separated(&code, ',')
+}
+
+// Sources
+pub fn load_file<P: AsRef<Path>>(path: P) -> Result<Vec<isize>,String> {
+    let source = fs::read_to_string(&path).map_err(|err| format!("Couldn't read {}: {}", path.as_ref().display(), err) )?;
+    parse_program(&source).map_err(|err| format!("{}: {}", path.as_ref().display(), err) )
+}
+pub fn load_stdin() -> Result<Vec<isize>,String> {
+    let mut source = String::new();
+    stdin().lock().read_to_string(&mut source).map_err(|err| format!("Couldn't read standard input: {}", err) )?;
+    parse_program(&source).map_err(|err| format!("Standard input: {}", err) )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn location(source: &str) -> Result<Vec<isize>,(usize,usize,String,String)> {
+        parse_program(source).map_err(|err| (err.line, err.column, err.token, err.message))
+    }
+
+    #[test]
+    fn layout_and_comments() {
+        assert_eq!(parse_program("# Program\n1, 2,\n  3 # third\n,-4,\n"), Ok(vec![1, 2, 3, -4]));
+        assert_eq!(parse_program(""), Ok(vec![]));
+    }
+    #[test]
+    fn error_locations() {
+        assert_eq!(location("1,,2"), Err((1, 3, ",".to_string(), "Empty token".to_string())));
+        assert_eq!(location("\n ,1"), Err((2, 2, ",".to_string(), "Empty token".to_string())));
+        assert_eq!(location("1,2 3"), Err((1, 5, "3".to_string(), "Expected ',' after token '2'".to_string())));
+        assert_eq!(location("1,2 # 3\n4"), Err((2, 1, "4".to_string(), "Expected ',' after token '2'".to_string())));
+        assert_eq!(location("1,\n  x5,6"), Err((2, 3, "x5".to_string(), "x5".parse::<isize>().unwrap_err().to_string())));
+        assert_eq!(location("1,\n99999999999999999999"), Err((2, 1, "99999999999999999999".to_string(), "99999999999999999999".parse::<isize>().unwrap_err().to_string())));
+    }
+}
@@ -0,0 +1,373 @@
+// Imports
+use std::{
+    collections::{HashMap,VecDeque},
+    convert::TryFrom,
+    ops::Range
+};
+use crate::{
+    decode::ParameterMode,
+    device::Device,
+    input::InputSource,
+    extension::{Extension,ExtensionContext},
+    coverage::Coverage,
+    replay::{Event,Recording},
+    timer::{Timer,TimerAction}
+};
+
+// Public
+pub enum IntcodeResult {
+    OUTPUT(isize),
+    HALT
+}
+
+pub struct Processor {
+    intcode: Vec<isize>,
+    position: usize,
+    relative_base: usize,
+    input: VecDeque<isize>,
+    input_source: Option<Box<dyn InputSource + Send>>,
+    error: Option<String>,
+    instructions: usize,
+    coverage: Option<Coverage>,
+    recording: Option<Recording>,
+    extensions: HashMap<isize,Box<dyn Extension + Send>>,
+    devices: Vec<(Range<usize>,Box<dyn Device + Send>)>,
+    timer: Option<Timer>,
+    #[cfg(feature = "jit")]
+    jit: Option<crate::jit::Jit>
+}
+impl Processor {
+    // State
+    pub fn new(intcode: Vec<isize>, input: Vec<isize>) -> Self {
+        Self {
+            intcode,
+            position: 0,
+            relative_base: 0,
+            input: input.into(),
+            input_source: None,
+            error: None,
+            instructions: 0,
+            coverage: None,
+            recording: None,
+            extensions: HashMap::new(),
+            devices: vec![],
+            timer: None,
+            #[cfg(feature = "jit")]
+            jit: None
+        }
+    }
+    // Continue from a saved state
+    pub(crate) fn set_registers(&mut self, position: usize, relative_base: usize) {
+        self.position = position;
+        self.relative_base = relative_base;
+    }
+    // Queued input is consumed before the input source
+    pub fn input_mut(&mut self) -> &mut VecDeque<isize> {
+        &mut self.input
+    }
+    pub fn set_input_source(&mut self, source: Box<dyn InputSource + Send>) {
+        self.input_source = Some(source);
+    }
+    // Error which ended iteration
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+    pub fn memory(&self) -> &[isize] {
+        &self.intcode
+    }
+    pub fn position(&self) -> usize {
+        self.position
+    }
+    pub fn relative_base(&self) -> usize {
+        self.relative_base
+    }
+    pub fn instructions(&self) -> usize {
+        self.instructions
+    }
+    pub fn enable_coverage(&mut self) {
+        self.coverage.get_or_insert_with(Coverage::new);
+    }
+    pub fn coverage(&self) -> Option<&Coverage> {
+        self.coverage.as_ref()
+    }
+    pub fn enable_recording(&mut self) {
+        self.recording.get_or_insert_with(Recording::new);
+    }
+    pub fn recording(&self) -> Option<&Recording> {
+        self.recording.as_ref()
+    }
+    pub fn register_extension(&mut self, opcode: isize, extension: Box<dyn Extension + Send>) -> Result<(),String> {
+        match opcode {
+            // 98 returns from timer handlers
+            1..=9 | 98 | 99 => Err(format!("Operation code {} is reserved!", opcode)),
+            10..=97 => {
+                self.extensions.insert(opcode, extension);
+                Ok(())
+            }
+            _ => Err(format!("Operation code {} out of range!", opcode))
+        }
+    }
+
+    // Timer interrupt every interval of executed instructions
+    pub fn set_timer(&mut self, interval: usize, action: TimerAction) -> Result<(),String> {
+        self.timer = Some(Timer::new(interval, action)?);
+        Ok(())
+    }
+    pub fn clear_timer(&mut self) {
+        self.timer = None;
+    }
+
+    // Native code for straight-line blocks, unused with coverage, devices or timer
+    #[cfg(feature = "jit")]
+    pub fn enable_jit(&mut self) {
+        self.jit.get_or_insert_with(crate::jit::Jit::new);
+    }
+
+    // Memory-mapped I/O, addresses of the range go to the device instead of memory
+    pub fn attach_device(&mut self, range: Range<usize>, device: Box<dyn Device + Send>) -> Result<(),String> {
+        if range.is_empty() {
+            return Err(format!("Device range {:?} is empty!", range));
+        }
+        if let Some((attached, _)) = self.devices.iter().find(|(attached, _)| attached.start < range.end && range.start < attached.end) {
+            return Err(format!("Device range {:?} overlaps attached range {:?}!", range, attached));
+        }
+        self.devices.push((range, device));
+        Ok(())
+    }
+
+    // Helpers
+    fn next_input(&mut self) -> Result<Option<isize>,String> {
+        match (self.input.pop_front(), &mut self.input_source) {
+            (Some(input), _) => Ok(Some(input)),
+            (None, Some(source)) => source.next_input(),
+            (None, None) => Ok(None)
+        }
+    }
+    fn provide_space(&mut self, pos: usize) {
+        if pos >= self.intcode.len() {
+            self.intcode.resize(pos+1, 0);
+        }
+    }
+    fn get_intcode_mut(&mut self, pos: usize) -> &mut isize {
+        self.provide_space(pos);
+        &mut self.intcode[pos]
+    }
+    // Memory access, intercepted by attached devices
+    pub(crate) fn read_intcode(&mut self, pos: usize) -> isize {
+        match self.devices.iter_mut().find(|(range, _)| range.contains(&pos)) {
+            Some((range, device)) => device.read(pos - range.start),
+            None => *self.get_intcode_mut(pos)
+        }
+    }
+    pub(crate) fn write_intcode(&mut self, pos: usize, value: isize) {
+        match self.devices.iter_mut().find(|(range, _)| range.contains(&pos)) {
+            Some((range, device)) => device.write(pos - range.start, value),
+            None => *self.get_intcode_mut(pos) = value
+        }
+    }
+    pub(crate) fn get_intcode_as_pos(&mut self, pos: usize, param_mode: ParameterMode) -> Result<usize,String> {
+        match param_mode {
+            ParameterMode::POSITION | ParameterMode::RELATIVE => {
+                let mut rel_pos = self.read_intcode(pos);
+                if param_mode == ParameterMode::RELATIVE {
+                    rel_pos += self.relative_base as isize;
+                }
+                Ok(rel_pos as usize)
+            }
+            ParameterMode::IMMEDIATE => Err(format!("Immediate intcode at {} can't be a position!", pos))
+        }
+    }
+    pub(crate) fn get_intcode_unpacked(&mut self, pos: usize, param_mode: ParameterMode) -> Result<isize,String> {
+        match param_mode {
+            ParameterMode::POSITION | ParameterMode::RELATIVE => {
+                let pos = self.get_intcode_as_pos(pos, param_mode)?;
+                Ok(self.read_intcode(pos))
+            }
+            ParameterMode::IMMEDIATE => Ok(self.read_intcode(pos))
+        }
+    }
+
+    // Main methods
+    pub fn process(&mut self) -> Result<IntcodeResult,String> {
+        loop {
+            #[cfg(feature = "jit")]
+            {
+                if self.step_jit() {
+                    continue;
+                }
+            }
+            if let Some(result) = self.step()? {
+                return Ok(result);
+            }
+        }
+    }
+    // Stops after reaching the breakpoint by at least one instruction
+    pub fn process_until(&mut self, breakpoint: usize) -> Result<Option<IntcodeResult>,String> {
+        loop {
+            if let Some(result) = self.step()? {
+                return Ok(Some(result));
+            }
+            if self.position == breakpoint {
+                return Ok(None);
+            }
+        }
+    }
+    #[cfg(feature = "jit")]
+    // Whether compiled code made progress, the interpreter takes over otherwise
+    fn step_jit(&mut self) -> bool {
+        if self.coverage.is_some() || !self.devices.is_empty() || self.timer.is_some() {
+            return false;
+        }
+        let instructions = self.instructions;
+        if let Some(jit) = &mut self.jit {
+            if let Some(next) = jit.execute(self.position, &mut self.intcode, &mut self.relative_base, &mut self.instructions) {
+                self.position = next;
+            }
+        }
+        self.instructions != instructions
+    }
+    pub fn step(&mut self) -> Result<Option<IntcodeResult>,String> {
+        if let Some(timer) = &mut self.timer {
+            timer.interrupt(&mut self.position, self.relative_base, &mut self.input);
+        }
+        // Next code
+        let position = self.position;
+        let result = match self.intcode.get(position) {
+            Some(&opcode) => self.execute(opcode)?,
+            // Halt by end of codes
+            None => return Ok(Some(IntcodeResult::HALT))
+        };
+        // Count executed instructions only, failed ones (like on missing input) can be retried
+        self.instructions += 1;
+        if let Some(timer) = &mut self.timer {
+            timer.tick();
+        }
+        if let Some(coverage) = &mut self.coverage {
+            coverage.record_instruction(position);
+        }
+        Ok(result)
+    }
+    // Instruction at current position
+    fn execute(&mut self, opcode: isize) -> Result<Option<IntcodeResult>,String> {
+        let (param_modes, instruction) = (opcode / 100, opcode % 100);
+        match instruction {
+            // Add or multiply
+            1 | 2 => {
+                let (param1, param2) = (
+                    self.get_intcode_unpacked(self.position+1, ParameterMode::try_from((param_modes, 0))?)?,
+                    self.get_intcode_unpacked(self.position+2, ParameterMode::try_from((param_modes, 1))?)?
+                );
+                let intcode_pos = self.get_intcode_as_pos(self.position+3, ParameterMode::try_from((param_modes, 2))?)?;
+                self.write_intcode(intcode_pos, match instruction {
+                    1 => param1 + param2,
+                    2 => param1 * param2,
+                    _ => unreachable!()
+                });
+                self.position += 4;
+            }
+            // Input
+            3 => {
+                let intcode_pos = self.get_intcode_as_pos(self.position+1, ParameterMode::try_from((param_modes, 0))?)?;
+                let input = self.next_input()?.ok_or("Input is missing!".to_string())?;
+                self.write_intcode(intcode_pos, input);
+                if let Some(recording) = &mut self.recording {
+                    recording.record(Event::INPUT(self.instructions + 1, input));
+                }
+                self.position += 2;
+            }
+            // Output
+            4 => {
+                let output = self.get_intcode_unpacked(self.position+1, ParameterMode::try_from((param_modes, 0))?)?;
+                self.position += 2;
+                if let Some(recording) = &mut self.recording {
+                    recording.record(Event::OUTPUT(self.instructions + 1, output));
+                }
+                return Ok(Some(IntcodeResult::OUTPUT(output)));
+            }
+            // Jump
+            5 | 6 => {
+                let param1 = self.get_intcode_unpacked(self.position+1, ParameterMode::try_from((param_modes, 0))?)?;
+                let jump = instruction == 5 && param1 != 0 || instruction == 6 && param1 == 0;
+                let target = if jump {
+                    self.get_intcode_unpacked(self.position+2, ParameterMode::try_from((param_modes, 1))?)? as usize
+                } else {
+                    self.position + 3
+                };
+                if let Some(coverage) = &mut self.coverage {
+                    coverage.record_branch(self.position, jump);
+                }
+                self.position = target;
+            }
+            // Less-than | equals
+            7 | 8 => {
+                let (param1, param2) = (
+                    self.get_intcode_unpacked(self.position+1, ParameterMode::try_from((param_modes, 0))?)?,
+                    self.get_intcode_unpacked(self.position+2, ParameterMode::try_from((param_modes, 1))?)?
+                );
+                let intcode_pos = self.get_intcode_as_pos(self.position+3, ParameterMode::try_from((param_modes, 2))?)?;
+                self.write_intcode(intcode_pos,
+                    if instruction == 7 && param1 < param2 || instruction == 8 && param1 == param2 {
+                        1
+                    } else {
+                        0
+                    }
+                );
+                self.position += 4;
+            }
+            // Offset relative base
+            9 => {
+                self.relative_base = (self.relative_base as isize + self.get_intcode_unpacked(self.position+1, ParameterMode::try_from((param_modes, 0))?)?) as usize;
+                self.position += 2;
+            }
+            // Halt!
+            99 => return Ok(Some(IntcodeResult::HALT)),
+            // Return from timer handler
+            98 if self.timer.as_ref().is_some_and(Timer::in_handler) => {
+                let (position, relative_base) = self.timer.as_mut().and_then(Timer::return_from_handler).expect("Timer should be in handler!");
+                self.position = position;
+                self.relative_base = relative_base;
+            }
+            // Extension
+            _ if self.extensions.contains_key(&instruction) => {
+                let mut extension = self.extensions.remove(&instruction).expect("Extension should be registered!");
+                let mut context = ExtensionContext::new(self, param_modes);
+                let result = extension.execute(&mut context);
+                let jump = context.jump_target();
+                if result.is_ok() {
+                    self.position = jump.unwrap_or(self.position + 1 + extension.parameters());
+                }
+                self.extensions.insert(instruction, extension);
+                return result;
+            }
+            // Invalid!
+            _ => return Err(format!("Invalid operation code {} at {}!", opcode, self.position))
+        }
+        Ok(None)
+    }
+}
+
+// Outputs until halt or error
+impl Iterator for Processor {
+    type Item = isize;
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.process() {
+            Ok(IntcodeResult::OUTPUT(output)) => Some(output),
+            Ok(IntcodeResult::HALT) => None,
+            Err(err) => {
+                self.error = Some(err);
+                None
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn processor_is_send() {
+        fn assert_send<T: Send>(_: T) {}
+        assert_send(Processor::new(vec![99], vec![]));
+    }
+}
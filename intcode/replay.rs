@@ -0,0 +1,188 @@
+// Imports
+use std::{
+    fmt,
+    fs,
+    path::Path,
+    str::FromStr
+};
+use crate::processor::{IntcodeResult,Processor};
+
+// I/O event with instruction count when it happened
+#[derive(Debug,Clone,Copy,Eq,PartialEq)]
+pub enum Event {
+    INPUT(usize, isize),
+    OUTPUT(usize, isize)
+}
+impl fmt::Display for Event {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::INPUT(instructions, value) => write!(f, "in {} {}", instructions, value),
+            Self::OUTPUT(instructions, value) => write!(f, "out {} {}", instructions, value)
+        }
+    }
+}
+impl FromStr for Event {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let tokens = s.split_whitespace().collect::<Vec<_>>();
+        if tokens.len() != 3 {
+            return Err(format!("Event needs kind, instruction count and value: {}", s));
+        }
+        let instructions = tokens[1].parse().map_err(|_| format!("Invalid instruction count for event: {}", tokens[1]) )?;
+        let value = tokens[2].parse().map_err(|_| format!("Invalid value for event: {}", tokens[2]) )?;
+        match tokens[0] {
+            "in" => Ok(Self::INPUT(instructions, value)),
+            "out" => Ok(Self::OUTPUT(instructions, value)),
+            kind => Err(format!("Invalid event kind: {}", kind))
+        }
+    }
+}
+
+// Session log
+#[derive(Debug,Clone,Default,Eq,PartialEq)]
+pub struct Recording {
+    events: Vec<Event>
+}
+impl Recording {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn record(&mut self, event: Event) {
+        self.events.push(event);
+    }
+    pub fn events(&self) -> &[Event] {
+        &self.events
+    }
+    pub fn inputs(&self) -> Vec<isize> {
+        self.events.iter()
+            .filter_map(|event| match event {
+                Event::INPUT(_, value) => Some(*value),
+                Event::OUTPUT(..) => None
+            })
+            .collect()
+    }
+
+    // Files
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(),String> {
+        fs::write(&path, self.to_string()).map_err(|err| format!("Couldn't write replay file {}: {}", path.as_ref().display(), err) )
+    }
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self,String> {
+        fs::read_to_string(&path)
+            .map_err(|err| format!("Couldn't read replay file {}: {}", path.as_ref().display(), err) )?
+            .parse()
+    }
+}
+impl fmt::Display for Recording {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "# Intcode replay")?;
+        for event in &self.events {
+            writeln!(f, "{}", event)?;
+        }
+        Ok(())
+    }
+}
+impl FromStr for Recording {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self {
+            events: s.lines()
+                .enumerate()
+                .filter(|(_, line)| !line.trim().is_empty() && !line.trim_start().starts_with('#'))
+                .map(|(i, line)| line.parse().map_err(|err| format!("Line {}: {}", i+1, err)))
+                .collect::<Result<_,_>>()?
+        })
+    }
+}
+
+// First event of a replay which didn't match the recording, none expected after its end but halt
+#[derive(Debug,Clone,Eq,PartialEq)]
+pub struct Divergence {
+    pub index: usize,
+    pub expected: Option<Event>,
+    pub actual: Result<Event,String>
+}
+impl fmt::Display for Divergence {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let expected = match &self.expected {
+            Some(expected) => format!("'{}'", expected),
+            None => "halt".to_string()
+        };
+        match &self.actual {
+            Ok(actual) => write!(f, "Event {} diverged: expected {}, got '{}'", self.index, expected, actual),
+            Err(err) => write!(f, "Event {} diverged: expected {}, got {}", self.index, expected, err)
+        }
+    }
+}
+
+// Run program with recorded inputs until halt (within a step limit) and verify all events, without further ones
+pub fn replay(intcode: Vec<isize>, recording: &Recording, steps: usize) -> Result<(),Divergence> {
+    let mut processor = Processor::new(intcode, recording.inputs());
+    processor.enable_recording();
+    let (expected, mut verified) = (recording.events(), 0);
+    for _ in 0..steps {
+        let step = processor.step();
+        // New events to verify?
+        let events = processor.recording().expect("Recording should be enabled!").events();
+        if let Some((index, actual)) = events.iter().enumerate().skip(verified).find(|(index, event)| expected.get(*index) != Some(*event)) {
+            return Err(Divergence {
+                index,
+                expected: expected.get(index).copied(),
+                actual: Ok(*actual)
+            });
+        }
+        verified = events.len();
+        // Session ended, too early or by error?
+        let end = match step {
+            Ok(Some(IntcodeResult::HALT)) if verified == expected.len() => return Ok(()),
+            Ok(Some(IntcodeResult::HALT)) => "halt".to_string(),
+            Err(err) => format!("error '{}'", err),
+            _ => continue
+        };
+        return Err(Divergence {
+            index: verified,
+            expected: expected.get(verified).copied(),
+            actual: Err(end)
+        });
+    }
+    Err(Divergence {
+        index: verified,
+        expected: expected.get(verified).copied(),
+        actual: Err(format!("no halt within {} steps", steps))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resumed_session() {
+        let intcode = vec![3,0,4,0,99];
+        let mut processor = Processor::new(intcode.clone(), vec![]);
+        processor.enable_recording();
+        assert_eq!(processor.process().err().as_deref(), Some("Input is missing!"));
+        processor.input_mut().push_back(5);
+        assert_eq!(processor.by_ref().collect::<Vec<_>>(), [5]);
+        assert_eq!(processor.error(), None);
+        let recording = processor.recording().expect("Recording should be enabled!").clone();
+        assert_eq!(recording.to_string(), "# Intcode replay\nin 1 5\nout 2 5\n");
+        assert_eq!(replay(intcode, &recording, 100), Ok(()));
+    }
+    #[test]
+    fn extra_output() {
+        let recording = "in 1 5".parse::<Recording>().expect("Recording should be valid!");
+        assert_eq!(
+            replay(vec![3,0,4,0,99], &recording, 100).map_err(|err| err.to_string()),
+            Err("Event 1 diverged: expected halt, got 'out 2 5'".to_string())
+        );
+    }
+    #[test]
+    fn no_events() {
+        assert_eq!(replay(vec![99], &Recording::new(), 100), Ok(()));
+        // Endless loop
+        assert_eq!(
+            replay(vec![1105,1,0], &Recording::new(), 100).map_err(|err| err.to_string()),
+            Err("Event 0 diverged: expected halt, got no halt within 100 steps".to_string())
+        );
+    }
+}
@@ -0,0 +1,126 @@
+// Imports
+use std::{
+    sync::{
+        atomic::{AtomicUsize,Ordering},
+        Mutex
+    },
+    thread
+};
+use crate::processor::Processor;
+
+// Threads to use by default
+pub fn available_threads() -> usize {
+    thread::available_parallelism().map_or(1, |threads| threads.get())
+}
+
+// Evaluate inputs in chunks taken by worker threads, results by input index
+fn evaluate_parallel<T,R,F>(inputs: &[T], threads: usize, stop_at_first: bool, evaluate: F) -> Vec<(usize,R)>
+    where T: Sync, R: Send, F: Fn(&T) -> Option<R> + Sync {
+    let chunk_size = (inputs.len() / (threads.max(1) * 8)).max(1);
+    let (next_chunk, first_found) = (AtomicUsize::new(0), AtomicUsize::new(usize::MAX));
+    let results = Mutex::new(vec![]);
+    thread::scope(|scope| {
+        for _ in 0..threads.max(1) {
+            scope.spawn(|| {
+                let mut found = vec![];
+                loop {
+                    let start = next_chunk.fetch_add(1, Ordering::Relaxed) * chunk_size;
+                    // Nothing left or only inputs behind an already found match
+                    if start >= inputs.len() || stop_at_first && start > first_found.load(Ordering::Relaxed) {
+                        break;
+                    }
+                    for (index, input) in inputs.iter().enumerate().skip(start).take(chunk_size) {
+                        if let Some(result) = evaluate(input) {
+                            found.push((index, result));
+                            if stop_at_first {
+                                first_found.fetch_min(index, Ordering::Relaxed);
+                                break;
+                            }
+                        }
+                    }
+                }
+                results.lock().expect("Search results poisoned!").extend(found);
+            });
+        }
+    });
+    let mut results = results.into_inner().expect("Search results poisoned!");
+    results.sort_by_key(|(index, _)| *index);
+    results
+}
+
+// First input in iteration order with a result
+pub fn find_first<I,R,F>(inputs: I, threads: usize, evaluate: F) -> Option<(I::Item,R)>
+    where I: IntoIterator, I::Item: Send + Sync, R: Send, F: Fn(&I::Item) -> Option<R> + Sync {
+    let mut inputs = inputs.into_iter().collect::<Vec<_>>();
+    let (index, result) = evaluate_parallel(&inputs, threads, true, evaluate).into_iter().next()?;
+    Some((inputs.swap_remove(index), result))
+}
+// Input with highest result, earliest in iteration order on ties
+pub fn find_best<I,R,F>(inputs: I, threads: usize, evaluate: F) -> Option<(I::Item,R)>
+    where I: IntoIterator, I::Item: Send + Sync, R: Send + Ord, F: Fn(&I::Item) -> Option<R> + Sync {
+    let mut inputs = inputs.into_iter().collect::<Vec<_>>();
+    let (index, result) = evaluate_parallel(&inputs, threads, false, evaluate).into_iter()
+        .fold(None, |best: Option<(usize,R)>, (index, result)| match best {
+            Some(best) if best.1 >= result => Some(best),
+            _ => Some((index, result))
+        })?;
+    Some((inputs.swap_remove(index), result))
+}
+
+// Run processor until halt, collecting outputs
+pub fn run_to_halt(processor: &mut Processor) -> Result<Vec<isize>,String> {
+    let outputs = processor.by_ref().collect();
+    match processor.error() {
+        Some(err) => Err(err.to_string()),
+        None => Ok(outputs)
+    }
+}
+// Searches on processors by factory, judged after halt (failing processors never match)
+pub fn search_first<I,R,F,J>(inputs: I, threads: usize, factory: F, judge: J) -> Option<(I::Item,R)>
+    where I: IntoIterator, I::Item: Send + Sync, R: Send, F: Fn(&I::Item) -> Processor + Sync, J: Fn(&I::Item, &Processor, &[isize]) -> Option<R> + Sync {
+    find_first(inputs, threads, |input| {
+        let mut processor = factory(input);
+        #[cfg(feature = "jit")]
+        processor.enable_jit();
+        let outputs = run_to_halt(&mut processor).ok()?;
+        judge(input, &processor, &outputs)
+    })
+}
+pub fn search_best<I,R,F,J>(inputs: I, threads: usize, factory: F, judge: J) -> Option<(I::Item,R)>
+    where I: IntoIterator, I::Item: Send + Sync, R: Send + Ord, F: Fn(&I::Item) -> Processor + Sync, J: Fn(&I::Item, &Processor, &[isize]) -> Option<R> + Sync {
+    find_best(inputs, threads, |input| {
+        let mut processor = factory(input);
+        #[cfg(feature = "jit")]
+        processor.enable_jit();
+        let outputs = run_to_halt(&mut processor).ok()?;
+        judge(input, &processor, &outputs)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Square of the input
+    const SQUARE: &[isize] = &[3,9,2,9,9,9,4,9,99,0];
+
+    #[test]
+    fn threads_agree() {
+        for threads in [1, 2, 8] {
+            assert_eq!(find_first(0..1000, threads, |x| if x % 7 == 3 && *x > 500 {Some(x * 2)} else {None}), Some((507, 1014)));
+            assert_eq!(find_first(0..1000, threads, |_| None::<i32>), None);
+            // Ties resolved by iteration order
+            assert_eq!(find_best(0..1000, threads, |x| Some(x % 100)), Some((99, 99)));
+        }
+    }
+    #[test]
+    fn processor_searches() {
+        for threads in [1, 2, 8] {
+            let factory = |input: &isize| Processor::new(SQUARE.to_vec(), vec![*input]);
+            assert_eq!(search_first(-10..=10, threads, factory, |_, _, outputs| if outputs == [49] {Some(outputs[0])} else {None}), Some((-7, 49)));
+            assert_eq!(search_best(-10..=10, threads, factory, |_, _, outputs| outputs.first().copied()), Some((-10, 100)));
+            // Failing processors never match
+            assert_eq!(search_first(0..10, threads, |_| Processor::new(SQUARE.to_vec(), vec![]), |_, _, _| Some(0)), None);
+        }
+    }
+}
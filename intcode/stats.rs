@@ -0,0 +1,166 @@
+// Imports
+use std::{
+    collections::{BTreeMap,BTreeSet},
+    convert::TryFrom,
+    fmt
+};
+use crate::{
+    decode::*,
+    processor::Processor,
+    replay::Event
+};
+
+// Program properties without running it, based on instructions reachable from start
+#[derive(Debug,Clone,Default,Eq,PartialEq)]
+pub struct StaticStats {
+    pub length: usize,
+    pub instructions: usize,
+    pub operations: BTreeMap<&'static str,usize>,
+    pub position_parameters: usize,
+    pub immediate_parameters: usize,
+    pub relative_parameters: usize,
+    pub code_cells: usize,
+    pub data_cells: usize,
+    pub max_address: Option<usize>,
+    pub immediate_writes: Vec<usize>
+}
+impl StaticStats {
+    pub fn analyze(intcode: &[isize]) -> Self {
+        let code = discover(intcode);
+        let mut stats = Self {
+            length: intcode.len(),
+            instructions: code.len(),
+            ..Self::default()
+        };
+        for instruction in code.values() {
+            *stats.operations.entry(instruction.operation.mnemonic()).or_insert(0) += 1;
+            for param in &instruction.parameters {
+                match param.mode {
+                    ParameterMode::POSITION => {
+                        stats.position_parameters += 1;
+                        if param.value >= 0 {
+                            stats.max_address = stats.max_address.max(Some(param.value as usize));
+                        }
+                    }
+                    ParameterMode::IMMEDIATE => stats.immediate_parameters += 1,
+                    ParameterMode::RELATIVE => stats.relative_parameters += 1
+                }
+            }
+        }
+        let code_cells = code.values()
+            .flat_map(|instruction| instruction.address..instruction.next().min(intcode.len()))
+            .collect::<BTreeSet<_>>();
+        stats.code_cells = code_cells.len();
+        stats.data_cells = intcode.len() - code_cells.len();
+        // Reachable addresses which didn't decode because of an immediate write target
+        let reached = code.values().flat_map(Instruction::successors).chain(Some(0)).collect::<BTreeSet<_>>();
+        stats.immediate_writes = reached.into_iter()
+            .filter(|address| !code.contains_key(address))
+            .filter(|address| intcode.get(*address).is_some_and(|&opcode|
+                Operation::try_from(opcode).is_ok_and(|operation|
+                    operation.writes() && ParameterMode::try_from((opcode / 100, operation.parameters() as u8 - 1)) == Ok(ParameterMode::IMMEDIATE)
+                )
+            ))
+            .collect();
+        stats
+    }
+}
+impl fmt::Display for StaticStats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Length: {} cells", self.length)?;
+        writeln!(f, "Code: {} cells ({} instructions), data: {} cells", self.code_cells, self.instructions, self.data_cells)?;
+        writeln!(f, "Operations: {}", self.operations.iter()
+            .map(|(mnemonic, count)| format!("{} {}", mnemonic, count))
+            .collect::<Vec<_>>()
+            .join(", "))?;
+        writeln!(f, "Parameters: position {}, immediate {}, relative {}", self.position_parameters, self.immediate_parameters, self.relative_parameters)?;
+        match self.max_address {
+            Some(address) => writeln!(f, "Max. address referenced: {}{}", address, if address >= self.length {" (beyond program)"} else {""})?,
+            None => writeln!(f, "Max. address referenced: none")?
+        }
+        writeln!(f, "Relative mode: {}", if self.relative_parameters > 0 {"used"} else {"unused"})?;
+        if self.immediate_writes.is_empty() {
+            write!(f, "Immediate writes: none")
+        } else {
+            write!(f, "Immediate writes: at {}", self.immediate_writes.iter()
+                .map(|address| address.to_string())
+                .collect::<Vec<_>>()
+                .join(", "))
+        }
+    }
+}
+
+// Properties of a run until halt or error
+#[derive(Debug,Clone,Default,Eq,PartialEq)]
+pub struct DynamicStats {
+    pub peak_memory: usize,
+    pub instructions: usize,
+    pub inputs: usize,
+    pub outputs: usize,
+    pub error: Option<String>
+}
+impl DynamicStats {
+    pub fn measure(intcode: Vec<isize>, input: Vec<isize>) -> Self {
+        let mut processor = Processor::new(intcode, input);
+        processor.enable_recording();
+        processor.by_ref().for_each(drop);
+        let events = processor.recording().expect("Recording should be enabled!").events();
+        Self {
+            // Memory only grows
+            peak_memory: processor.memory().len(),
+            instructions: processor.instructions(),
+            inputs: events.iter().filter(|event| matches!(event, Event::INPUT(..))).count(),
+            outputs: events.iter().filter(|event| matches!(event, Event::OUTPUT(..))).count(),
+            error: processor.error().map(str::to_string)
+        }
+    }
+}
+impl fmt::Display for DynamicStats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Peak memory: {} cells", self.peak_memory)?;
+        writeln!(f, "Instructions executed: {}", self.instructions)?;
+        write!(f, "Inputs: {}, outputs: {}", self.inputs, self.outputs)?;
+        match &self.error {
+            Some(err) => write!(f, "\nError: {}", err),
+            None => Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Day 9 quine
+    const QUINE: &[isize] = &[109,1,204,-1,1001,100,1,100,1008,100,16,101,1006,101,0,99];
+
+    #[test]
+    fn static_counts() {
+        let stats = StaticStats::analyze(QUINE);
+        assert_eq!((stats.length, stats.instructions, stats.code_cells, stats.data_cells), (16, 6, 16, 0));
+        assert_eq!(stats.operations.into_iter().collect::<Vec<_>>(), [("add", 1), ("eq", 1), ("hlt", 1), ("jz", 1), ("out", 1), ("rbo", 1)]);
+        assert_eq!((stats.position_parameters, stats.immediate_parameters, stats.relative_parameters), (5, 4, 1));
+        assert_eq!(stats.max_address, Some(101));
+        assert!(stats.immediate_writes.is_empty());
+        // Write target in immediate mode doesn't decode
+        let stats = StaticStats::analyze(&[11101,1,2,3,99]);
+        assert_eq!((stats.instructions, stats.data_cells, stats.immediate_writes), (0, 5, vec![0]));
+    }
+    #[test]
+    fn dynamic_counts() {
+        assert_eq!(DynamicStats::measure(QUINE.to_vec(), vec![]), DynamicStats {
+            peak_memory: 102,
+            instructions: 16 * 5 + 1,
+            inputs: 0,
+            outputs: 16,
+            error: None
+        });
+        assert_eq!(DynamicStats::measure(vec![3,9,8,9,10,9,4,9,99,-1,8], vec![]), DynamicStats {
+            peak_memory: 11,
+            instructions: 0,
+            inputs: 0,
+            outputs: 0,
+            error: Some("Input is missing!".to_string())
+        });
+    }
+}
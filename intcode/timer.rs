@@ -0,0 +1,91 @@
+// Imports
+use std::collections::VecDeque;
+
+// Reaction of a firing timer
+#[derive(Debug,Clone,Copy,Eq,PartialEq)]
+pub enum TimerAction {
+    // Value queued as input
+    INPUT(isize),
+    // Jump to handler address, returning by opcode 98 (timer masked until then)
+    HANDLER(usize)
+}
+
+// Timer counting executed instructions
+#[derive(Debug,Clone)]
+pub(crate) struct Timer {
+    interval: usize,
+    countdown: usize,
+    action: TimerAction,
+    interrupted: Option<(usize,usize)>
+}
+impl Timer {
+    pub(crate) fn new(interval: usize, action: TimerAction) -> Result<Self,String> {
+        match interval {
+            0 => Err("Timer interval must be positive!".to_string()),
+            _ => Ok(Self {
+                interval,
+                countdown: interval,
+                action,
+                interrupted: None
+            })
+        }
+    }
+    pub(crate) fn tick(&mut self) {
+        self.countdown = self.countdown.saturating_sub(1);
+    }
+    pub(crate) fn in_handler(&self) -> bool {
+        self.interrupted.is_some()
+    }
+    // Fire if due, saving position and relative base on entering the handler
+    pub(crate) fn interrupt(&mut self, position: &mut usize, relative_base: usize, input: &mut VecDeque<isize>) {
+        if self.countdown > 0 {
+            return;
+        }
+        self.countdown = self.interval;
+        match self.action {
+            TimerAction::INPUT(value) => input.push_back(value),
+            TimerAction::HANDLER(_) if self.in_handler() => (),
+            TimerAction::HANDLER(address) => {
+                self.interrupted = Some((*position, relative_base));
+                *position = address;
+            }
+        }
+    }
+    // Interrupted position and relative base
+    pub(crate) fn return_from_handler(&mut self) -> Option<(usize,usize)> {
+        self.interrupted.take()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::processor::Processor;
+    use super::*;
+
+    #[test]
+    fn input_after_interval() {
+        // Two additions before reading and echoing the input
+        let intcode = vec![1101,0,0,20,1101,0,0,20,3,20,4,20,99];
+        let mut processor = Processor::new(intcode.clone(), vec![]);
+        processor.set_timer(2, TimerAction::INPUT(42)).expect("Interval should be valid!");
+        assert_eq!(processor.by_ref().collect::<Vec<_>>(), [42]);
+        assert_eq!(processor.error(), None);
+        // Not fired yet when reading
+        let mut processor = Processor::new(intcode, vec![]);
+        processor.set_timer(3, TimerAction::INPUT(42)).expect("Interval should be valid!");
+        assert_eq!(processor.by_ref().count(), 0);
+        assert_eq!(processor.error(), Some("Input is missing!"));
+        assert!(Processor::new(vec![99], vec![]).set_timer(0, TimerAction::INPUT(0)).is_err());
+    }
+    #[test]
+    fn handler_after_interval() {
+        // Counter incremented in an endless loop, handler at 10 outputs it and returns
+        let mut processor = Processor::new(vec![1001,30,1,30,1105,1,0,0,0,0,4,30,98], vec![]);
+        processor.set_timer(5, TimerAction::HANDLER(10)).expect("Interval should be valid!");
+        assert_eq!(processor.next(), Some(3));
+        assert_eq!((processor.instructions(), processor.position()), (6, 12));
+        // Returned to the loop, next interrupt 5 instructions after the last one
+        assert_eq!(processor.next(), Some(4));
+        assert_eq!(processor.instructions(), 11);
+    }
+}
@@ -0,0 +1,257 @@
+// Imports
+use std::{
+    collections::BTreeSet,
+    fmt::Write
+};
+use crate::decode::*;
+
+// Runtime shared by all modules: memory access with growth, self-modification guard & interpreter fallback
+const RUNTIME: &str = r#"
+  ;; Byte offset of cell, growing memory (and program length) on demand
+  (func $addr (param $cell i64) (result i32)
+    (local $pages i32)
+    (if (i64.ge_u (local.get $cell) (i64.const 0x1fffffff)) (then unreachable))
+    (local.set $pages (i32.wrap_i64 (i64.shr_u (i64.add (i64.mul (local.get $cell) (i64.const 8)) (i64.const 65543)) (i64.const 16))))
+    (if (i32.gt_u (local.get $pages) (memory.size))
+      (then (if (i32.eq (memory.grow (i32.sub (local.get $pages) (memory.size))) (i32.const -1)) (then unreachable))))
+    (if (i64.ge_u (local.get $cell) (global.get $length)) (then (global.set $length (i64.add (local.get $cell) (i64.const 1)))))
+    (i32.wrap_i64 (i64.mul (local.get $cell) (i64.const 8))))
+  (func $load (param $cell i64) (result i64)
+    (i64.load (call $addr (local.get $cell))))
+  (func $store (param $cell i64) (param $value i64)
+    (if (call $compiled (local.get $cell)) (then (global.set $modified (i32.const 1))))
+    (i64.store (call $addr (local.get $cell)) (local.get $value)))
+
+  ;; Interpreter for self-modified code and addresses unknown at compile time
+  (func $mode (param $pc i64) (param $n i32) (result i64)
+    (local $modes i64)
+    (local.set $modes (i64.div_s (call $load (local.get $pc)) (i64.const 100)))
+    (block $done
+      (loop $shift
+        (br_if $done (i32.eqz (local.get $n)))
+        (local.set $modes (i64.div_s (local.get $modes) (i64.const 10)))
+        (local.set $n (i32.sub (local.get $n) (i32.const 1)))
+        (br $shift)))
+    (i64.rem_s (local.get $modes) (i64.const 10)))
+  (func $cell (param $pc i64) (param $n i32) (result i64)
+    (local $mode i64)
+    (local $raw i64)
+    (local.set $mode (call $mode (local.get $pc) (local.get $n)))
+    (local.set $raw (call $load (i64.add (local.get $pc) (i64.extend_i32_u (i32.add (local.get $n) (i32.const 1))))))
+    (if (i64.eqz (local.get $mode)) (then (return (local.get $raw))))
+    (if (i64.eq (local.get $mode) (i64.const 2)) (then (return (i64.add (global.get $rb) (local.get $raw)))))
+    unreachable)
+  (func $read (param $pc i64) (param $n i32) (result i64)
+    (if (i64.eq (call $mode (local.get $pc) (local.get $n)) (i64.const 1))
+      (then (return (call $load (i64.add (local.get $pc) (i64.extend_i32_u (i32.add (local.get $n) (i32.const 1))))))))
+    (call $load (call $cell (local.get $pc) (local.get $n))))
+  (func $interpret (param $pc i64)
+    (local $op i64)
+    (loop $step
+      (if (i64.ge_u (local.get $pc) (global.get $length)) (then (return)))
+      (local.set $op (i64.rem_s (call $load (local.get $pc)) (i64.const 100)))
+      (if (i64.eq (local.get $op) (i64.const 99)) (then (return)))
+      (block $rbo
+        (block $eq
+          (block $lt
+            (block $jz
+              (block $jnz
+                (block $out
+                  (block $in
+                    (block $mul
+                      (block $add
+                        (block $invalid
+                          (br_table $invalid $add $mul $in $out $jnz $jz $lt $eq $rbo $invalid
+                            (i32.wrap_i64 (select (local.get $op) (i64.const 0) (i64.le_u (local.get $op) (i64.const 9))))))
+                        unreachable)
+                      (call $store (call $cell (local.get $pc) (i32.const 2)) (i64.add (call $read (local.get $pc) (i32.const 0)) (call $read (local.get $pc) (i32.const 1))))
+                      (local.set $pc (i64.add (local.get $pc) (i64.const 4)))
+                      (br $step))
+                    (call $store (call $cell (local.get $pc) (i32.const 2)) (i64.mul (call $read (local.get $pc) (i32.const 0)) (call $read (local.get $pc) (i32.const 1))))
+                    (local.set $pc (i64.add (local.get $pc) (i64.const 4)))
+                    (br $step))
+                  (call $store (call $cell (local.get $pc) (i32.const 0)) (call $input))
+                  (local.set $pc (i64.add (local.get $pc) (i64.const 2)))
+                  (br $step))
+                (call $output (call $read (local.get $pc) (i32.const 0)))
+                (local.set $pc (i64.add (local.get $pc) (i64.const 2)))
+                (br $step))
+              (local.set $pc (select (call $read (local.get $pc) (i32.const 1)) (i64.add (local.get $pc) (i64.const 3)) (i64.ne (call $read (local.get $pc) (i32.const 0)) (i64.const 0))))
+              (br $step))
+            (local.set $pc (select (call $read (local.get $pc) (i32.const 1)) (i64.add (local.get $pc) (i64.const 3)) (i64.eqz (call $read (local.get $pc) (i32.const 0)))))
+            (br $step))
+          (call $store (call $cell (local.get $pc) (i32.const 2)) (i64.extend_i32_u (i64.lt_s (call $read (local.get $pc) (i32.const 0)) (call $read (local.get $pc) (i32.const 1)))))
+          (local.set $pc (i64.add (local.get $pc) (i64.const 4)))
+          (br $step))
+        (call $store (call $cell (local.get $pc) (i32.const 2)) (i64.extend_i32_u (i64.eq (call $read (local.get $pc) (i32.const 0)) (call $read (local.get $pc) (i32.const 1)))))
+        (local.set $pc (i64.add (local.get $pc) (i64.const 4)))
+        (br $step))
+      (global.set $rb (i64.add (global.get $rb) (call $read (local.get $pc) (i32.const 0))))
+      (local.set $pc (i64.add (local.get $pc) (i64.const 2)))
+      (br $step)))
+"#;
+
+// Expressions for parameters
+fn operand(parameter: &Parameter) -> String {
+    match parameter.mode {
+        ParameterMode::POSITION => format!("(call $load (i64.const {}))", parameter.value),
+        ParameterMode::IMMEDIATE => format!("(i64.const {})", parameter.value),
+        ParameterMode::RELATIVE => format!("(call $load (i64.add (global.get $rb) (i64.const {})))", parameter.value)
+    }
+}
+fn target(parameter: &Parameter) -> String {
+    match parameter.mode {
+        ParameterMode::RELATIVE => format!("(i64.add (global.get $rb) (i64.const {}))", parameter.value),
+        _ => format!("(i64.const {})", parameter.value)
+    }
+}
+
+// Body of compiled instruction, leaving by setting the position and branching to dispatch
+fn compile_instruction(instruction: &Instruction, falls_through: bool) -> Vec<String> {
+    let params = &instruction.parameters;
+    let store = |value: String| vec![
+        format!("(call $store {} {})", target(&params[params.len()-1]), value),
+        format!("(local.set $pc (i64.const {}))", instruction.next()),
+        "(br_if $dispatch (global.get $modified))".to_string()
+    ];
+    let mut lines = match instruction.operation {
+        Operation::ADD => store(format!("(i64.add {} {})", operand(&params[0]), operand(&params[1]))),
+        Operation::MULTIPLY => store(format!("(i64.mul {} {})", operand(&params[0]), operand(&params[1]))),
+        Operation::LESSTHAN => store(format!("(i64.extend_i32_u (i64.lt_s {} {}))", operand(&params[0]), operand(&params[1]))),
+        Operation::EQUALS => store(format!("(i64.extend_i32_u (i64.eq {} {}))", operand(&params[0]), operand(&params[1]))),
+        Operation::INPUT => store("(call $input)".to_string()),
+        Operation::OUTPUT => vec![format!("(call $output {})", operand(&params[0]))],
+        Operation::RELBASE => vec![format!("(global.set $rb (i64.add (global.get $rb) {}))", operand(&params[0]))],
+        Operation::JUMPTRUE | Operation::JUMPFALSE => vec![
+            format!("(local.set $pc (i64.const {}))", instruction.next()),
+            format!("(if ({} {}) (then (local.set $pc {})))",
+                if instruction.operation == Operation::JUMPTRUE {"i64.ne (i64.const 0)"} else {"i64.eq (i64.const 0)"},
+                operand(&params[0]), operand(&params[1])),
+            "(br $dispatch)".to_string()
+        ],
+        Operation::HALT => vec!["(return)".to_string()]
+    };
+    if !falls_through && !matches!(instruction.operation, Operation::JUMPTRUE | Operation::JUMPFALSE | Operation::HALT) {
+        lines.push(format!("(local.set $pc (i64.const {}))", instruction.next()));
+        lines.push("(br $dispatch)".to_string());
+    }
+    lines
+}
+
+// WebAssembly text module running the program, importing env.input/env.output and exporting run & memory (i64 cells)
+pub fn compile(intcode: &[isize]) -> String {
+    let code = discover(intcode).into_values().collect::<Vec<_>>();
+    let code_cells = code.iter()
+        .flat_map(|instruction| instruction.address..instruction.next())
+        .collect::<BTreeSet<_>>();
+    let mut wat = String::new();
+    writeln!(wat, "(module").unwrap();
+    writeln!(wat, "  ;; Intcode program of {} cells, {} instructions compiled", intcode.len(), code.len()).unwrap();
+    writeln!(wat, "  (import \"env\" \"input\" (func $input (result i64)))").unwrap();
+    writeln!(wat, "  (import \"env\" \"output\" (func $output (param i64)))").unwrap();
+    writeln!(wat, "  (memory (export \"memory\") {})", (intcode.len() * 8).div_ceil(65536).max(1)).unwrap();
+    let data = intcode.iter()
+        .flat_map(|value| (*value as i64).to_le_bytes())
+        .map(|byte| format!("\\{:02x}", byte))
+        .collect::<String>();
+    writeln!(wat, "  (data (i32.const 0) \"{}\")", data).unwrap();
+    writeln!(wat, "  (global $length (mut i64) (i64.const {}))", intcode.len()).unwrap();
+    writeln!(wat, "  (global $rb (mut i64) (i64.const 0))").unwrap();
+    writeln!(wat, "  (global $modified (mut i32) (i32.const 0))").unwrap();
+    // Guard by ranges of compiled cells
+    writeln!(wat, "  (func $compiled (param $cell i64) (result i32)").unwrap();
+    let mut ranges: Vec<(usize,usize)> = vec![];
+    for cell in code_cells {
+        match ranges.last_mut() {
+            Some(range) if range.1 == cell => range.1 += 1,
+            _ => ranges.push((cell, cell + 1))
+        }
+    }
+    for (start, end) in ranges {
+        writeln!(wat, "    (if (i32.and (i64.ge_u (local.get $cell) (i64.const {})) (i64.lt_u (local.get $cell) (i64.const {}))) (then (return (i32.const 1))))", start, end).unwrap();
+    }
+    writeln!(wat, "    (i32.const 0))").unwrap();
+    wat.push_str(RUNTIME);
+    // Dispatch by position into nested blocks, one per instruction (innermost first)
+    writeln!(wat, "  (func (export \"run\")").unwrap();
+    writeln!(wat, "    (local $pc i64)").unwrap();
+    writeln!(wat, "    (loop $dispatch").unwrap();
+    writeln!(wat, "      (if (global.get $modified) (then (call $interpret (local.get $pc)) (return)))").unwrap();
+    writeln!(wat, "      (block $fallback").unwrap();
+    for instruction in code.iter().rev() {
+        writeln!(wat, "      (block $i{}", instruction.address).unwrap();
+    }
+    let table_size = code.last().map_or(0, |instruction| instruction.address + 1);
+    let mut table = vec!["$fallback".to_string(); table_size];
+    for instruction in &code {
+        table[instruction.address] = format!("$i{}", instruction.address);
+    }
+    writeln!(wat, "        (br_if $fallback (i64.ge_u (local.get $pc) (i64.const {})))", table_size).unwrap();
+    writeln!(wat, "        (br_table {} $fallback (i32.wrap_i64 (local.get $pc))))", table.join(" ")).unwrap();
+    for (index, instruction) in code.iter().enumerate() {
+        let falls_through = code.get(index + 1).is_some_and(|next| next.address == instruction.next());
+        writeln!(wat, "      ;; {:05}: {}", instruction.address, instruction).unwrap();
+        for line in compile_instruction(instruction, falls_through) {
+            writeln!(wat, "      {}", line).unwrap();
+        }
+        writeln!(wat, "      )").unwrap();
+    }
+    writeln!(wat, "      (call $interpret (local.get $pc))))").unwrap();
+    writeln!(wat, ")").unwrap();
+    wat
+}
+
+#[cfg(test)]
+mod tests {
+    use super::compile;
+
+    // Parentheses balance outside strings and comments, every branch label is declared
+    fn check_structure(wat: &str) {
+        let (mut depth, mut in_string, mut labels, mut branches) = (0isize, false, vec![], vec![]);
+        for line in wat.lines() {
+            let line = line.split(";;").next().unwrap_or("");
+            for chr in line.chars() {
+                match chr {
+                    '"' => in_string = !in_string,
+                    '(' if !in_string => depth += 1,
+                    ')' if !in_string => depth -= 1,
+                    _ => ()
+                }
+                assert!(depth >= 0, "Unbalanced parenthesis: {}", line);
+            }
+            let tokens = line.split(|chr: char| chr.is_whitespace() || chr == '(' || chr == ')').collect::<Vec<_>>();
+            for window in tokens.windows(2) {
+                match window[0] {
+                    "block" | "loop" => labels.push(window[1].to_string()),
+                    "br" | "br_if" => branches.push(window[1].to_string()),
+                    _ => ()
+                }
+            }
+            if let Some(table) = line.split("br_table ").nth(1) {
+                branches.extend(table.split_whitespace().take_while(|token| token.starts_with('$')).map(str::to_string));
+            }
+        }
+        assert_eq!(depth, 0);
+        for branch in branches {
+            assert!(labels.contains(&branch), "Undeclared label {}", branch);
+        }
+    }
+
+    #[test]
+    fn emits_valid_structure() {
+        let programs: &[&[isize]] = &[
+            &[109,1,204,-1,1001,100,1,100,1008,100,16,101,1006,101,0,99],
+            &[1102,34915192,34915192,7,4,7,99,0],
+            &[3,9,8,9,10,9,4,9,99,-1,8],
+            &[99]
+        ];
+        for intcode in programs {
+            let wat = compile(intcode);
+            assert!(wat.starts_with("(module"));
+            assert!(wat.contains("(import \"env\" \"input\" (func $input (result i64)))"));
+            assert!(wat.contains("(import \"env\" \"output\" (func $output (param i64)))"));
+            assert!(wat.contains("(export \"run\")") && wat.contains("(export \"memory\")"));
+            check_structure(&wat);
+        }
+    }
+}
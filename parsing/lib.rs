@@ -0,0 +1,269 @@
+// Input parsing library
+use std::{
+    fmt::{self,Display},
+    io::{stdin,Read},
+    ops::RangeInclusive,
+    str::FromStr
+};
+
+// Whole standard input
+pub fn read_stdin() -> Result<String,String> {
+    let mut input = String::new();
+    stdin().lock().read_to_string(&mut input).map_err(|err| format!("Couldn't read standard input: {}", err) )?;
+    Ok(input)
+}
+
+// Location (1-based) and reason of invalid input
+#[derive(Debug,Clone,Eq,PartialEq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub token: String,
+    pub message: String
+}
+impl ParseError {
+    fn new(line: usize, column: usize, token: &str, message: &str) -> Self {
+        Self {
+            line,
+            column,
+            token: token.to_string(),
+            message: message.to_string()
+        }
+    }
+}
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Line {}, column {}: {} (token '{}')", self.line, self.column, self.message, self.token)
+    }
+}
+// For solvers with text errors
+impl From<ParseError> for String {
+    fn from(err: ParseError) -> Self {
+        err.to_string()
+    }
+}
+
+// Token of a line, trimmed, with its column
+#[derive(Debug,Clone,Copy)]
+struct Token<'a> {
+    text: &'a str,
+    line: usize,
+    column: usize
+}
+impl<'a> Token<'a> {
+    fn new(line: usize, column: usize, text: &'a str) -> Self {
+        let trimmed = text.trim_start();
+        Self {
+            text: trimmed.trim_end(),
+            line,
+            column: column + text[..text.len() - trimmed.len()].chars().count()
+        }
+    }
+    fn error(&self, message: &str) -> ParseError {
+        ParseError::new(self.line, self.column, self.text, message)
+    }
+    fn parse<T>(&self) -> Result<T,ParseError> where T: FromStr, T::Err: Display {
+        if self.text.is_empty() {
+            return Err(self.error("Empty token"));
+        }
+        self.text.parse().map_err(|err: T::Err| self.error(&err.to_string()))
+    }
+    // Parts before and after first delimiter
+    fn split_once(&self, delimiter: char) -> Result<(Token<'a>,Token<'a>),ParseError> {
+        let index = self.text.find(delimiter).ok_or(self.error(&format!("Missing delimiter '{}'", delimiter)))?;
+        Ok((
+            Token::new(self.line, self.column, &self.text[..index]),
+            Token::new(self.line, self.column + self.text[..index].chars().count() + 1, &self.text[index + delimiter.len_utf8()..])
+        ))
+    }
+    fn split(&self, separator: char) -> impl Iterator<Item=Token<'a>> {
+        let (line, mut column) = (self.line, self.column);
+        self.text.split(separator).map(move |text| {
+            let token = Token::new(line, column, text);
+            column += text.chars().count() + 1;
+            token
+        })
+    }
+}
+// Non-blank lines as tokens
+fn lines(text: &str) -> impl Iterator<Item=Token<'_>> {
+    text.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| Token::new(index + 1, 1, line))
+}
+
+// One value per line
+pub fn parse_lines<T>(text: &str) -> Result<Vec<T>,ParseError> where T: FromStr, T::Err: Display {
+    lines(text).map(|line| line.parse()).collect()
+}
+// Values separated by a character, like comma-separated integers, continuing over lines (whitespace around values, trailing separator tolerated)
+pub fn parse_separated<T>(text: &str, separator: char) -> Result<Vec<T>,ParseError> where T: FromStr, T::Err: Display {
+    let mut values = vec![];
+    // Value waiting for its separator
+    let mut token: Option<Token> = None;
+    for (line_index, line) in text.lines().enumerate() {
+        // Byte index and column of value being read
+        let mut start: Option<(usize,usize)> = None;
+        for (column_index, (index, chr)) in line.char_indices().enumerate() {
+            let (line_number, column) = (line_index + 1, column_index + 1);
+            if chr == separator || chr.is_whitespace() {
+                if let Some((start_index, start_column)) = start.take() {
+                    token = Some(Token::new(line_number, start_column, &line[start_index..index]));
+                }
+                if chr == separator {
+                    match token.take() {
+                        Some(token) => values.push(token.parse()?),
+                        None => return Err(ParseError::new(line_number, column, &chr.to_string(), "Empty token"))
+                    }
+                }
+            } else if start.is_none() {
+                if let Some(token) = &token {
+                    return Err(ParseError::new(line_number, column, &chr.to_string(), &format!("Expected '{}' after token '{}'", separator, token.text)));
+                }
+                start = Some((index, column));
+            }
+        }
+        // Line end closes values
+        if let Some((start_index, start_column)) = start {
+            token = Some(Token::new(line_index + 1, start_column, &line[start_index..]));
+        }
+    }
+    if let Some(token) = token {
+        values.push(token.parse()?);
+    }
+    Ok(values)
+}
+// Separated values per line, like moves of wires
+pub fn parse_lists<T>(text: &str, separator: char) -> Result<Vec<Vec<T>>,ParseError> where T: FromStr, T::Err: Display {
+    lines(text).map(|line| line.split(separator).map(|token| token.parse()).collect()).collect()
+}
+// Two values per line around a delimiter, like 'A)B'
+pub fn parse_pairs<A,B>(text: &str, delimiter: char) -> Result<Vec<(A,B)>,ParseError>
+    where A: FromStr, A::Err: Display, B: FromStr, B::Err: Display {
+    lines(text)
+        .map(|line| {
+            let (first, second) = line.split_once(delimiter)?;
+            Ok((first.parse()?, second.parse()?))
+        })
+        .collect()
+}
+// Range in format 'start-end'
+pub fn parse_range<T>(text: &str) -> Result<RangeInclusive<T>,ParseError> where T: FromStr + PartialOrd, T::Err: Display {
+    let line = lines(text).next().ok_or(ParseError::new(1, 1, "", "Missing range"))?;
+    let (start, end) = line.split_once('-')?;
+    let range = start.parse()? ..= end.parse()?;
+    if range.start() > range.end() {
+        return Err(line.error("Range start is after its end"));
+    }
+    Ok(range)
+}
+
+// Decimal digits as numbers, line breaks ignored
+pub fn parse_digits(text: &str) -> Result<Vec<u8>,ParseError> {
+    Ok(parse_digit_grid(text)?.concat())
+}
+// Decimal digits by row
+pub fn parse_digit_grid(text: &str) -> Result<Vec<Vec<u8>>,ParseError> {
+    lines(text)
+        .map(|line| line.text.chars()
+            .enumerate()
+            .map(|(index, chr)| chr.to_digit(10)
+                .map(|digit| digit as u8)
+                .ok_or(ParseError::new(line.line, line.column + index, &chr.to_string(), "Invalid digit"))
+            )
+            .collect()
+        )
+        .collect()
+}
+
+// Rectangular grid of characters, addressed by (column, row) from top-left
+#[derive(Debug,Clone,Eq,PartialEq)]
+pub struct Grid {
+    width: usize,
+    cells: Vec<char>
+}
+impl Grid {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+    pub fn height(&self) -> usize {
+        self.cells.len().checked_div(self.width).unwrap_or(0)
+    }
+    pub fn get(&self, column: usize, row: usize) -> Option<char> {
+        if column < self.width {self.cells.get(row * self.width + column).copied()} else {None}
+    }
+    pub fn cells(&self) -> impl Iterator<Item=((usize,usize),char)> + '_ {
+        self.cells.iter().enumerate().map(move |(index, chr)| ((index % self.width, index / self.width), *chr))
+    }
+    // Coordinates of a marker
+    pub fn positions(&self, marker: char) -> Vec<(usize,usize)> {
+        self.cells().filter(|(_, chr)| *chr == marker).map(|(position, _)| position).collect()
+    }
+}
+// Lines of same length with allowed characters only
+pub fn parse_grid(text: &str, allowed: &str) -> Result<Grid,ParseError> {
+    let mut grid = Grid {width: 0, cells: vec![]};
+    for (row, line) in text.trim_end_matches(&['\r','\n'][..]).lines().enumerate() {
+        let (line_number, width) = (row + 1, line.chars().count());
+        if row == 0 {
+            grid.width = width;
+        } else if width != grid.width {
+            return Err(ParseError::new(line_number, width.min(grid.width) + 1, line, &format!("Row length {} differs from first row's {}", width, grid.width)));
+        }
+        for (column, chr) in line.chars().enumerate() {
+            if !allowed.contains(chr) {
+                return Err(ParseError::new(line_number, column + 1, &chr.to_string(), &format!("Expected one of '{}'", allowed)));
+            }
+            grid.cells.push(chr);
+        }
+    }
+    Ok(grid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn values() {
+        assert_eq!(parse_lines::<u32>("12\n 14 \n\n1969\n"), Ok(vec![12, 14, 1969]));
+        assert_eq!(parse_lines::<u32>("12\n1x"), Err(ParseError::new(2, 1, "1x", &"1x".parse::<u32>().unwrap_err().to_string())));
+        assert_eq!(parse_separated::<isize>("1,-2, 3\n,4,\n", ','), Ok(vec![1, -2, 3, 4]));
+        assert_eq!(parse_separated::<isize>(" \n", ','), Ok(vec![]));
+        assert_eq!(parse_separated::<isize>("1,2,,4", ',').map_err(|err| (err.line, err.column, err.message)), Err((1, 5, "Empty token".to_string())));
+        assert_eq!(parse_separated::<isize>("\n ,1", ',').map_err(|err| (err.line, err.column, err.message)), Err((2, 2, "Empty token".to_string())));
+        assert_eq!(parse_separated::<isize>("1,2\n3", ',').map_err(|err| (err.line, err.column, err.token, err.message)), Err((2, 1, "3".to_string(), "Expected ',' after token '2'".to_string())));
+        assert_eq!(parse_separated::<isize>("1, x5 ,6", ',').map_err(|err| (err.line, err.column, err.token)), Err((1, 4, "x5".to_string())));
+        assert_eq!(parse_lists::<String>("R8,U5\nU7", ',').map_err(|err| err.to_string()), Ok(vec![vec!["R8".to_string(), "U5".to_string()], vec!["U7".to_string()]]));
+        assert_eq!(parse_lists::<u8>("1,2\n3,x4", ',').map_err(|err| (err.line, err.column, err.token)), Err((2, 3, "x4".to_string())));
+    }
+    #[test]
+    fn pairs_and_ranges() {
+        assert_eq!(parse_pairs::<String,String>("COM)B\nB)C", ')').map_err(|err| err.to_string()), Ok(vec![("COM".to_string(), "B".to_string()), ("B".to_string(), "C".to_string())]));
+        assert_eq!(parse_pairs::<String,String>("COM)B\nBC", ')').map_err(|err| (err.line, err.column, err.message)), Err((2, 1, "Missing delimiter ')'".to_string())));
+        assert_eq!(parse_pairs::<u8,u8>("1)x", ')').map_err(|err| (err.line, err.column)), Err((1, 3)));
+        assert_eq!(parse_range::<u32>("100-200\n"), Ok(100..=200));
+        assert_eq!(parse_range::<u32>("100-2x0").map_err(|err| err.column), Err(5));
+        assert!(parse_range::<u32>("200-100").is_err());
+        assert!(parse_range::<u32>("").is_err());
+        // Columns count characters, not bytes
+        assert_eq!(parse_pairs::<String,u8>("ä→1\nb→x", '→').map_err(|err| (err.line, err.column, err.token)), Err((2, 3, "x".to_string())));
+        assert_eq!(parse_pairs::<String,String>("ä→ö", '→').map_err(|err| err.to_string()), Ok(vec![("ä".to_string(), "ö".to_string())]));
+    }
+    #[test]
+    fn digits() {
+        assert_eq!(parse_digits("0120\n12\n"), Ok(vec![0, 1, 2, 0, 1, 2]));
+        assert_eq!(parse_digit_grid("12\n34"), Ok(vec![vec![1, 2], vec![3, 4]]));
+        assert_eq!(parse_digits("12\n3a4"), Err(ParseError::new(2, 2, "a", "Invalid digit")));
+    }
+    #[test]
+    fn grid() {
+        let grid = parse_grid(".#.\n#..\n", ".#").expect("Grid should be valid!");
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.positions('#'), [(1, 0), (0, 1)]);
+        assert_eq!((grid.get(1, 0), grid.get(3, 0), grid.get(0, 2)), (Some('#'), None, None));
+        assert_eq!(parse_grid(".#.\n#.", ".#").map_err(|err| (err.line, err.column)), Err((2, 3)));
+        assert_eq!(parse_grid(".#.\n#X.", ".#").map_err(|err| (err.line, err.column, err.token)), Err((2, 2, "X".to_string())));
+    }
+}
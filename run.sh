@@ -3,31 +3,22 @@
 # Create output directory
 mkdir -p out
 
-# Compile code and run executable
-build_and_run() {
-    echo "##### Day $1 #####"
-    # Rust (https://www.rust-lang.org/tools/install)
-    if [ -f day$1/main.rs ]; then
-        rustc day$1/main.rs --edition 2018 -O -v -o out/day$1 && \
-        cat day$1/input.txt | ./out/day$1
+# Kotlin (https://github.com/JetBrains/kotlin/releases/latest)
+if [ "$1" = "kotlin" ]; then
+    if [ -f day$2/main.kt ]; then
+        kotlinc -include-runtime -jvm-target 1.8 -d out/day$2.jar day$2/main.kt && \
+        cat day$2/input.txt | java -jar out/day$2.jar
+    else
+        echo "No Kotlin solution for day $2!"
+        exit 1
     fi
-    # Kotlin (https://github.com/JetBrains/kotlin/releases/latest)
-    if [ -f day$1/main.kt ]; then
-        kotlinc -include-runtime -jvm-target 1.8 -d out/day$1.jar day$1/main.kt
-        java -jar out/day$1.jar
-    fi
-    echo "##################"
-    echo
-}
+    exit
+fi
 
-# Days
-#build_and_run 1
-#build_and_run 2
-#build_and_run 3
-#build_and_run 4
-#build_and_run 5
-#build_and_run 6
-#build_and_run 7
-#build_and_run 8
-#build_and_run 9
-build_and_run 10
\ No newline at end of file
+# Rust (https://www.rust-lang.org/tools/install), features like 'CARGO_FLAGS="--features jit"'
+# Arguments go to the runner, by default all days: 'aoc run <day> [--part 1|2] [--input path]' or 'aoc run --all'
+if [ $# -eq 0 ]; then
+    set -- run --all
+fi
+cargo build --release -p aoc $CARGO_FLAGS && \
+./target/release/aoc "$@"